@@ -3,9 +3,14 @@
 //! This module provides various backoff strategies to control delay timing
 //! between retry attempts.
 
+use crate::jitter::Jitter;
+use core::time::Duration;
 use rand::Rng;
 use rand::RngExt;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use alloc::vec::Vec;
+
 /// `f64::powi`, implemented by hand so it works in `core` (`no_std`).
 #[inline]
 pub(crate) fn powi_f64(base: f64, exp: i32) -> f64 {
@@ -25,11 +30,17 @@ pub(crate) fn powi_f64(base: f64, exp: i32) -> f64 {
     acc
 }
 
+/// Largest `n` whose Fibonacci number fits in a `u64`.
+const MAX_FIBONACCI_N: u32 = 93;
+
 /// Calculate the nth Fibonacci number (1-indexed): 1, 1, 2, 3, 5, 8, 13, ...
-pub fn fibonacci(n: u8) -> u64 {
+///
+/// Saturates at `u64::MAX` once the sequence overflows (`n > 93`).
+pub fn fibonacci(n: u32) -> u64 {
     match n {
         0 => 0,
         1 | 2 => 1,
+        n if n > MAX_FIBONACCI_N => u64::MAX,
         _ => {
             let mut a = 1u64;
             let mut b = 1u64;
@@ -43,15 +54,58 @@ pub fn fibonacci(n: u8) -> u64 {
     }
 }
 
-/// Blend a base delay with jitter using the full-jitter algorithm.
+/// Multiply a delay by a growth factor and cap it, all in milliseconds.
+///
+/// A zero delay stays zero even when the factor overflows to infinity at
+/// large attempt numbers.
+fn capped_product(delay_ms: f64, factor: f64, max_ms: f64) -> f64 {
+    let product = delay_ms * factor;
+    if product.is_nan() {
+        0.0
+    } else {
+        product.min(max_ms)
+    }
+}
+
+/// Whether `attempt` has reached the attempt limit (`None` never does).
+pub(crate) fn attempts_exhausted(attempt: u32, max_attempts: Option<u32>) -> bool {
+    max_attempts.is_some_and(|max| attempt >= max)
+}
+
+/// Exponent for the given attempt (`attempt - 1`), clamped to the range of `i32`.
+fn attempt_exponent(attempt: u32) -> i32 {
+    attempt.saturating_sub(1).min(i32::MAX as u32) as i32
+}
+
+/// Apply a [`Jitter`] mode to a base delay in milliseconds.
+fn apply_jitter<R: Rng>(base: f64, jitter: Jitter, rng: &mut R) -> u64 {
+    jitter.apply(base, rng) as u64
+}
+
+/// Apply a [`Jitter`] mode to a base delay in milliseconds, keeping sub-millisecond precision.
+fn apply_jitter_duration<R: Rng>(base: f64, jitter: Jitter, rng: &mut R) -> Duration {
+    duration_from_ms_f64(jitter.apply(base, rng))
+}
+
+/// Convert fractional milliseconds to a [`Duration`].
 ///
-/// `jitter_factor` is clamped to `[0.0, 1.0]`: `0.0` returns `base` unchanged,
-/// `1.0` yields a uniform value in `[0, base]`.
-fn apply_jitter<R: Rng>(base: f64, jitter_factor: f64, rng: &mut R) -> u64 {
-    let jitter_factor = jitter_factor.clamp(0.0, 1.0);
-    let random_scalar: f64 = rng.random_range(0.0..=1.0);
-    let jitter_blend = 1.0 - jitter_factor + random_scalar * jitter_factor;
-    (base * jitter_blend) as u64
+/// Negative and `NaN` inputs map to zero; values too large for a `Duration`
+/// saturate to [`Duration::MAX`].
+pub(crate) fn duration_from_ms_f64(ms: f64) -> Duration {
+    if ms.is_nan() || ms <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f64(ms / 1000.0).unwrap_or(Duration::MAX)
+}
+
+/// Express a [`Duration`] as fractional milliseconds.
+pub(crate) fn duration_as_ms_f64(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Whole milliseconds in a [`Duration`], truncated and saturating at `u64::MAX`.
+pub(crate) fn duration_as_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
 }
 
 /// Trait for backoff strategies that calculate delays between retry attempts
@@ -66,7 +120,76 @@ pub trait BackoffStrategy {
     /// # Returns
     ///
     /// Delay in milliseconds, or `None` if retries should stop
-    fn delay<R: Rng>(&self, attempt: u8, rng: &mut R) -> Option<u64>;
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64>;
+
+    /// Calculate the delay for the given attempt from the delay that preceded it
+    ///
+    /// Stateful strategies such as [`DecorrelatedJitterBackoff`] derive each
+    /// delay from the previous one. The retry loop threads the last delay it
+    /// slept through this method; the default implementation ignores it and
+    /// defers to [`BackoffStrategy::delay`].
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Current attempt number (1-indexed)
+    /// * `previous_delay_ms` - Delay used before this attempt (`None` on the first retry)
+    /// * `rng` - Random number generator for jitter
+    ///
+    /// # Returns
+    ///
+    /// Delay in milliseconds, or `None` if retries should stop
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        let _ = previous_delay_ms;
+        self.delay(attempt, rng)
+    }
+
+    /// Calculate the delay for the given attempt as a [`Duration`]
+    ///
+    /// Unlike [`BackoffStrategy::delay`], the result is not truncated to whole
+    /// milliseconds. The default implementation defers to
+    /// [`BackoffStrategy::next_delay_duration`] with no previous delay.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Current attempt number (1-indexed)
+    /// * `rng` - Random number generator for jitter
+    ///
+    /// # Returns
+    ///
+    /// Delay before the next attempt, or `None` if retries should stop
+    fn delay_duration<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<Duration> {
+        self.next_delay_duration(attempt, None, rng)
+    }
+
+    /// Calculate the delay for the given attempt from the delay that preceded it, as a [`Duration`]
+    ///
+    /// This is what the retry loop calls. The default implementation converts
+    /// to and from the millisecond-based [`BackoffStrategy::next_delay`], so
+    /// strategies only need to override it to provide sub-millisecond delays.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Current attempt number (1-indexed)
+    /// * `previous_delay` - Delay used before this attempt (`None` on the first retry)
+    /// * `rng` - Random number generator for jitter
+    ///
+    /// # Returns
+    ///
+    /// Delay before the next attempt, or `None` if retries should stop
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        self.next_delay(attempt, previous_delay.map(duration_as_ms), rng)
+            .map(Duration::from_millis)
+    }
 
     /// Check if another retry should be attempted
     ///
@@ -77,10 +200,57 @@ pub trait BackoffStrategy {
     /// # Returns
     ///
     /// `true` if another retry is allowed, `false` otherwise
-    fn should_retry(&self, attempt: u8) -> bool;
+    fn should_retry(&self, attempt: u32) -> bool;
+
+    /// Maximum number of attempts permitted by this strategy, including the
+    /// first one. `None` means the strategy never gives up on its own.
+    fn max_attempts(&self) -> Option<u32>;
+
+    /// Total time budget for the retry operation, measured from the start of
+    /// the first attempt. `None` (the default) means no time limit.
+    fn max_elapsed(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl<B: BackoffStrategy> BackoffStrategy for &B {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        (**self).delay(attempt, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        (**self).next_delay(attempt, previous_delay_ms, rng)
+    }
+
+    fn delay_duration<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<Duration> {
+        (**self).delay_duration(attempt, rng)
+    }
 
-    /// Maximum number of retry attempts permitted by this strategy.
-    fn max_attempts(&self) -> u8;
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        (**self).next_delay_duration(attempt, previous_delay, rng)
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        (**self).should_retry(attempt)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        (**self).max_attempts()
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        (**self).max_elapsed()
+    }
 }
 
 /// Exponential backoff strategy with configurable jitter
@@ -91,6 +261,7 @@ pub trait BackoffStrategy {
 ///
 /// ```rust
 /// use chrono_machines::ExponentialBackoff;
+/// use core::time::Duration;
 ///
 /// let backoff = ExponentialBackoff::new()
 ///     .base_delay_ms(100)
@@ -98,19 +269,26 @@ pub trait BackoffStrategy {
 ///     .max_delay_ms(10_000)
 ///     .max_attempts(5)
 ///     .jitter_factor(1.0); // Full jitter
+///
+/// // Sub-millisecond delays for in-memory contention
+/// let spin = ExponentialBackoff::new()
+///     .base_delay(Duration::from_micros(100))
+///     .max_delay(Duration::from_millis(5));
 /// ```
 #[derive(Debug, Clone, Copy)]
 pub struct ExponentialBackoff {
-    /// Maximum number of retry attempts
-    pub max_attempts: u8,
-    /// Base delay in milliseconds
-    pub base_delay_ms: u64,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Base delay
+    pub base_delay: Duration,
     /// Exponential backoff multiplier
     pub multiplier: f64,
-    /// Maximum delay cap in milliseconds
-    pub max_delay_ms: u64,
-    /// Jitter factor (0.0 = no jitter, 1.0 = full jitter)
-    pub jitter_factor: f64,
+    /// Maximum delay cap
+    pub max_delay: Duration,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
 }
 
 impl ExponentialBackoff {
@@ -121,7 +299,13 @@ impl ExponentialBackoff {
 
     /// Set the base delay in milliseconds
     pub fn base_delay_ms(mut self, ms: u64) -> Self {
-        self.base_delay_ms = ms;
+        self.base_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the base delay
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
         self
     }
 
@@ -133,19 +317,45 @@ impl ExponentialBackoff {
 
     /// Set the maximum delay cap in milliseconds
     pub fn max_delay_ms(mut self, ms: u64) -> Self {
-        self.max_delay_ms = ms;
+        self.max_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the maximum delay cap
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
         self
     }
 
     /// Set the maximum number of attempts
-    pub fn max_attempts(mut self, attempts: u8) -> Self {
-        self.max_attempts = attempts;
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
         self
     }
 
     /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
     pub fn jitter_factor(mut self, factor: f64) -> Self {
-        self.jitter_factor = factor.clamp(0.0, 1.0);
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
         self
     }
 }
@@ -153,35 +363,59 @@ impl ExponentialBackoff {
 impl Default for ExponentialBackoff {
     fn default() -> Self {
         Self {
-            max_attempts: 3,
-            base_delay_ms: 100,
+            max_attempts: Some(3),
+            max_elapsed: None,
+            base_delay: Duration::from_millis(100),
             multiplier: 2.0,
-            max_delay_ms: 10_000,
-            jitter_factor: 1.0, // Full jitter by default
+            max_delay: Duration::from_millis(10_000),
+            jitter: Jitter::Full, // Full jitter by default
         }
     }
 }
 
-impl BackoffStrategy for ExponentialBackoff {
-    fn delay<R: Rng>(&self, attempt: u8, rng: &mut R) -> Option<u64> {
-        if attempt >= self.max_attempts {
+impl ExponentialBackoff {
+    /// Capped delay in milliseconds before jitter, or `None` once attempts are exhausted.
+    fn base_ms(&self, attempt: u32) -> Option<f64> {
+        if attempts_exhausted(attempt, self.max_attempts) {
             return None;
         }
 
-        let exponent = attempt.saturating_sub(1) as i32;
-        let base_exponential = (self.base_delay_ms as f64) * powi_f64(self.multiplier, exponent);
-        let capped = base_exponential.min(self.max_delay_ms as f64);
+        let growth = powi_f64(self.multiplier, attempt_exponent(attempt));
+        Some(capped_product(
+            duration_as_ms_f64(self.base_delay),
+            growth,
+            duration_as_ms_f64(self.max_delay),
+        ))
+    }
+}
+
+impl BackoffStrategy for ExponentialBackoff {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter(base, self.jitter, rng))
+    }
 
-        Some(apply_jitter(capped, self.jitter_factor, rng))
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter_duration(base, self.jitter, rng))
     }
 
-    fn should_retry(&self, attempt: u8) -> bool {
-        attempt < self.max_attempts
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
     }
 
-    fn max_attempts(&self) -> u8 {
+    fn max_attempts(&self) -> Option<u32> {
         self.max_attempts
     }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
 }
 
 /// Constant backoff strategy with fixed delay
@@ -200,12 +434,14 @@ impl BackoffStrategy for ExponentialBackoff {
 /// ```
 #[derive(Debug, Clone, Copy)]
 pub struct ConstantBackoff {
-    /// Fixed delay in milliseconds
-    pub delay_ms: u64,
-    /// Maximum number of retry attempts
-    pub max_attempts: u8,
-    /// Jitter factor (0.0 = no jitter, 1.0 = full jitter)
-    pub jitter_factor: f64,
+    /// Fixed delay
+    pub interval: Duration,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
 }
 
 impl ConstantBackoff {
@@ -216,19 +452,45 @@ impl ConstantBackoff {
 
     /// Set the constant delay in milliseconds
     pub fn delay_ms(mut self, ms: u64) -> Self {
-        self.delay_ms = ms;
+        self.interval = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the constant delay
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
         self
     }
 
     /// Set the maximum number of attempts
-    pub fn max_attempts(mut self, attempts: u8) -> Self {
-        self.max_attempts = attempts;
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
         self
     }
 
     /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
     pub fn jitter_factor(mut self, factor: f64) -> Self {
-        self.jitter_factor = factor.clamp(0.0, 1.0);
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
         self
     }
 }
@@ -236,29 +498,52 @@ impl ConstantBackoff {
 impl Default for ConstantBackoff {
     fn default() -> Self {
         Self {
-            delay_ms: 100,
-            max_attempts: 3,
-            jitter_factor: 0.0, // No jitter for constant by default
+            interval: Duration::from_millis(100),
+            max_attempts: Some(3),
+            max_elapsed: None,
+            jitter: Jitter::None, // No jitter for constant by default
         }
     }
 }
 
 impl BackoffStrategy for ConstantBackoff {
-    fn delay<R: Rng>(&self, attempt: u8, rng: &mut R) -> Option<u64> {
-        if attempt >= self.max_attempts {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        if attempts_exhausted(attempt, self.max_attempts) {
+            return None;
+        }
+
+        Some(apply_jitter(
+            duration_as_ms_f64(self.interval),
+            self.jitter,
+            rng,
+        ))
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        if attempts_exhausted(attempt, self.max_attempts) {
             return None;
         }
 
-        Some(apply_jitter(self.delay_ms as f64, self.jitter_factor, rng))
+        let base = duration_as_ms_f64(self.interval);
+        Some(apply_jitter_duration(base, self.jitter, rng))
     }
 
-    fn should_retry(&self, attempt: u8) -> bool {
-        attempt < self.max_attempts
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
     }
 
-    fn max_attempts(&self) -> u8 {
+    fn max_attempts(&self) -> Option<u32> {
         self.max_attempts
     }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
 }
 
 /// Fibonacci backoff strategy
@@ -279,14 +564,16 @@ impl BackoffStrategy for ConstantBackoff {
 /// ```
 #[derive(Debug, Clone, Copy)]
 pub struct FibonacciBackoff {
-    /// Base delay in milliseconds (multiplied by Fibonacci number)
-    pub base_delay_ms: u64,
-    /// Maximum delay cap in milliseconds
-    pub max_delay_ms: u64,
-    /// Maximum number of retry attempts
-    pub max_attempts: u8,
-    /// Jitter factor (0.0 = no jitter, 1.0 = full jitter)
-    pub jitter_factor: f64,
+    /// Base delay (multiplied by Fibonacci number)
+    pub base_delay: Duration,
+    /// Maximum delay cap
+    pub max_delay: Duration,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
 }
 
 impl FibonacciBackoff {
@@ -297,25 +584,57 @@ impl FibonacciBackoff {
 
     /// Set the base delay in milliseconds
     pub fn base_delay_ms(mut self, ms: u64) -> Self {
-        self.base_delay_ms = ms;
+        self.base_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the base delay
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
         self
     }
 
     /// Set the maximum delay cap in milliseconds
     pub fn max_delay_ms(mut self, ms: u64) -> Self {
-        self.max_delay_ms = ms;
+        self.max_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the maximum delay cap
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
         self
     }
 
     /// Set the maximum number of attempts
-    pub fn max_attempts(mut self, attempts: u8) -> Self {
-        self.max_attempts = attempts;
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
         self
     }
 
     /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
     pub fn jitter_factor(mut self, factor: f64) -> Self {
-        self.jitter_factor = factor.clamp(0.0, 1.0);
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
         self
     }
 }
@@ -323,146 +642,1093 @@ impl FibonacciBackoff {
 impl Default for FibonacciBackoff {
     fn default() -> Self {
         Self {
-            base_delay_ms: 100,
-            max_delay_ms: 10_000,
-            max_attempts: 8,
-            jitter_factor: 1.0, // Full jitter by default
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(10_000),
+            max_attempts: Some(8),
+            max_elapsed: None,
+            jitter: Jitter::Full, // Full jitter by default
         }
     }
 }
 
-impl BackoffStrategy for FibonacciBackoff {
-    fn delay<R: Rng>(&self, attempt: u8, rng: &mut R) -> Option<u64> {
-        if attempt >= self.max_attempts {
+impl FibonacciBackoff {
+    /// Capped delay in milliseconds before jitter, or `None` once attempts are exhausted.
+    fn base_ms(&self, attempt: u32) -> Option<f64> {
+        if attempts_exhausted(attempt, self.max_attempts) {
             return None;
         }
 
         let fib = fibonacci(attempt);
-        let base = ((self.base_delay_ms as f64) * (fib as f64)).min(self.max_delay_ms as f64);
+        Some(capped_product(
+            duration_as_ms_f64(self.base_delay),
+            fib as f64,
+            duration_as_ms_f64(self.max_delay),
+        ))
+    }
+}
+
+impl BackoffStrategy for FibonacciBackoff {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter(base, self.jitter, rng))
+    }
 
-        Some(apply_jitter(base, self.jitter_factor, rng))
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter_duration(base, self.jitter, rng))
     }
 
-    fn should_retry(&self, attempt: u8) -> bool {
-        attempt < self.max_attempts
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
     }
 
-    fn max_attempts(&self) -> u8 {
+    fn max_attempts(&self) -> Option<u32> {
         self.max_attempts
     }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
 }
 
-/// Backoff policy that can represent any supported strategy.
+/// Linear backoff strategy
 ///
-/// The enum form makes it possible to store heterogeneous strategies in a
-/// registry or configuration without heap allocation or dynamic dispatch.
+/// Delays grow by a fixed step: base_delay_ms + step_ms * (attempt-1).
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::LinearBackoff;
+///
+/// let backoff = LinearBackoff::new()
+///     .base_delay_ms(100)  // 100ms, 300ms, 500ms, 700ms...
+///     .step_ms(200)
+///     .max_delay_ms(5_000)
+///     .max_attempts(6);
+/// ```
 #[derive(Debug, Clone, Copy)]
-pub enum BackoffPolicy {
-    /// Exponential backoff policy
-    Exponential(ExponentialBackoff),
-    /// Constant backoff policy
-    Constant(ConstantBackoff),
-    /// Fibonacci backoff policy
-    Fibonacci(FibonacciBackoff),
+pub struct LinearBackoff {
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Added to the delay on every subsequent attempt
+    pub step: Duration,
+    /// Maximum delay cap
+    pub max_delay: Duration,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
 }
 
-impl BackoffPolicy {
-    /// Return the maximum retry attempts for the wrapped strategy.
-    pub fn max_attempts(&self) -> u8 {
-        match self {
-            BackoffPolicy::Exponential(policy) => policy.max_attempts,
-            BackoffPolicy::Constant(policy) => policy.max_attempts,
-            BackoffPolicy::Fibonacci(policy) => policy.max_attempts,
-        }
+impl LinearBackoff {
+    /// Create a new linear backoff builder with default values
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-impl BackoffStrategy for BackoffPolicy {
-    fn delay<R: Rng>(&self, attempt: u8, rng: &mut R) -> Option<u64> {
-        match self {
-            BackoffPolicy::Exponential(policy) => policy.delay(attempt, rng),
-            BackoffPolicy::Constant(policy) => policy.delay(attempt, rng),
-            BackoffPolicy::Fibonacci(policy) => policy.delay(attempt, rng),
-        }
+    /// Set the base delay in milliseconds
+    pub fn base_delay_ms(mut self, ms: u64) -> Self {
+        self.base_delay = Duration::from_millis(ms);
+        self
     }
 
-    fn should_retry(&self, attempt: u8) -> bool {
-        match self {
-            BackoffPolicy::Exponential(policy) => policy.should_retry(attempt),
-            BackoffPolicy::Constant(policy) => policy.should_retry(attempt),
-            BackoffPolicy::Fibonacci(policy) => policy.should_retry(attempt),
-        }
+    /// Set the base delay
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
     }
 
-    fn max_attempts(&self) -> u8 {
-        match self {
-            BackoffPolicy::Exponential(policy) => policy.max_attempts(),
-            BackoffPolicy::Constant(policy) => policy.max_attempts(),
-            BackoffPolicy::Fibonacci(policy) => policy.max_attempts(),
-        }
+    /// Set the step added per attempt in milliseconds
+    pub fn step_ms(mut self, ms: u64) -> Self {
+        self.step = Duration::from_millis(ms);
+        self
     }
-}
 
-impl From<ExponentialBackoff> for BackoffPolicy {
-    fn from(value: ExponentialBackoff) -> Self {
-        BackoffPolicy::Exponential(value)
+    /// Set the step added per attempt
+    pub fn step(mut self, step: Duration) -> Self {
+        self.step = step;
+        self
     }
-}
 
-impl From<ConstantBackoff> for BackoffPolicy {
-    fn from(value: ConstantBackoff) -> Self {
-        BackoffPolicy::Constant(value)
+    /// Set the maximum delay cap in milliseconds
+    pub fn max_delay_ms(mut self, ms: u64) -> Self {
+        self.max_delay = Duration::from_millis(ms);
+        self
     }
-}
 
-impl From<FibonacciBackoff> for BackoffPolicy {
-    fn from(value: FibonacciBackoff) -> Self {
-        BackoffPolicy::Fibonacci(value)
+    /// Set the maximum delay cap
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rand::rngs::StdRng;
-    use rand::SeedableRng;
+    /// Set the maximum number of attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
 
-    #[test]
-    fn test_exponential_backoff_builder() {
-        let backoff = ExponentialBackoff::new()
-            .base_delay_ms(200)
-            .multiplier(3.0)
-            .max_delay_ms(5000)
-            .max_attempts(5)
-            .jitter_factor(0.5);
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
 
-        assert_eq!(backoff.base_delay_ms, 200);
-        assert_eq!(backoff.multiplier, 3.0);
-        assert_eq!(backoff.max_delay_ms, 5000);
-        assert_eq!(backoff.max_attempts, 5);
-        assert_eq!(backoff.jitter_factor, 0.5);
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
     }
 
-    #[test]
-    fn test_exponential_delays() {
-        let backoff = ExponentialBackoff::new()
-            .base_delay_ms(100)
-            .multiplier(2.0)
-            .jitter_factor(0.0); // No jitter for predictable testing
+    /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
+    pub fn jitter_factor(mut self, factor: f64) -> Self {
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
 
-        let mut rng = StdRng::seed_from_u64(42);
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
 
-        assert_eq!(backoff.delay(1, &mut rng), Some(100));
-        assert_eq!(backoff.delay(2, &mut rng), Some(200));
-        assert_eq!(backoff.delay(3, &mut rng), None); // Exceeds max_attempts (default 3)
+impl Default for LinearBackoff {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            step: Duration::from_millis(100),
+            max_delay: Duration::from_millis(10_000),
+            max_attempts: Some(3),
+            max_elapsed: None,
+            jitter: Jitter::Full, // Full jitter by default
+        }
     }
+}
 
-    #[test]
-    fn test_constant_backoff() {
-        let backoff = ConstantBackoff::new()
-            .delay_ms(500)
-            .max_attempts(4)
-            .jitter_factor(0.0);
+impl LinearBackoff {
+    /// Capped delay in milliseconds before jitter, or `None` once attempts are exhausted.
+    fn base_ms(&self, attempt: u32) -> Option<f64> {
+        if attempts_exhausted(attempt, self.max_attempts) {
+            return None;
+        }
+
+        let steps = attempt.saturating_sub(1) as f64;
+        Some(
+            (duration_as_ms_f64(self.base_delay) + duration_as_ms_f64(self.step) * steps)
+                .min(duration_as_ms_f64(self.max_delay)),
+        )
+    }
+}
+
+impl BackoffStrategy for LinearBackoff {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter(base, self.jitter, rng))
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter_duration(base, self.jitter, rng))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+}
+
+/// Polynomial backoff strategy
+///
+/// Delays grow as a power of the attempt number: base_delay_ms * attempt^exponent.
+/// The exponent is an integer so the calculation stays available in `no_std`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::PolynomialBackoff;
+///
+/// let backoff = PolynomialBackoff::new()
+///     .base_delay_ms(50)   // 50ms, 200ms, 450ms, 800ms...
+///     .exponent(2)
+///     .max_delay_ms(5_000)
+///     .max_attempts(6);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PolynomialBackoff {
+    /// Base delay (multiplied by attempt^exponent)
+    pub base_delay: Duration,
+    /// Power applied to the attempt number
+    pub exponent: u32,
+    /// Maximum delay cap
+    pub max_delay: Duration,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
+}
+
+impl PolynomialBackoff {
+    /// Create a new polynomial backoff builder with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the base delay in milliseconds
+    pub fn base_delay_ms(mut self, ms: u64) -> Self {
+        self.base_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the base delay
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the power applied to the attempt number
+    pub fn exponent(mut self, exponent: u32) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Set the maximum delay cap in milliseconds
+    pub fn max_delay_ms(mut self, ms: u64) -> Self {
+        self.max_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the maximum delay cap
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the maximum number of attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
+    }
+
+    /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
+    pub fn jitter_factor(mut self, factor: f64) -> Self {
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl Default for PolynomialBackoff {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            exponent: 2,
+            max_delay: Duration::from_millis(10_000),
+            max_attempts: Some(3),
+            max_elapsed: None,
+            jitter: Jitter::Full, // Full jitter by default
+        }
+    }
+}
+
+impl PolynomialBackoff {
+    /// Capped delay in milliseconds before jitter, or `None` once attempts are exhausted.
+    fn base_ms(&self, attempt: u32) -> Option<f64> {
+        if attempts_exhausted(attempt, self.max_attempts) {
+            return None;
+        }
+
+        let exponent = self.exponent.min(i32::MAX as u32) as i32;
+        Some(capped_product(
+            duration_as_ms_f64(self.base_delay),
+            powi_f64(attempt as f64, exponent),
+            duration_as_ms_f64(self.max_delay),
+        ))
+    }
+}
+
+impl BackoffStrategy for PolynomialBackoff {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter(base, self.jitter, rng))
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let base = self.base_ms(attempt)?;
+        Some(apply_jitter_duration(base, self.jitter, rng))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+}
+
+/// Look up the delay in milliseconds for `attempt` in an explicit schedule,
+/// before jitter.
+///
+/// Attempts past the end of the schedule reuse its last entry, so a strategy
+/// allowing more attempts than scheduled delays keeps retrying at the final
+/// interval.
+fn schedule_base_ms(delays_ms: &[u64], attempt: u32, max_attempts: Option<u32>) -> Option<f64> {
+    if attempts_exhausted(attempt, max_attempts) {
+        return None;
+    }
+
+    let index = (attempt.max(1) as usize - 1).min(delays_ms.len().checked_sub(1)?);
+    Some(delays_ms[index] as f64)
+}
+
+/// Number of attempts needed to use every delay in a schedule once.
+const fn schedule_attempts(len: usize) -> Option<u32> {
+    if len >= u32::MAX as usize {
+        Some(u32::MAX)
+    } else {
+        Some(len as u32 + 1)
+    }
+}
+
+/// Explicit delay schedule strategy (requires `alloc`)
+///
+/// Retries wait for the listed delays in order: the first retry uses the
+/// first entry, the second retry the second entry, and so on. By default the
+/// strategy allows one attempt per scheduled delay plus the initial attempt;
+/// raising `max_attempts` repeats the last delay. Jitter is disabled by
+/// default so the schedule is followed exactly.
+///
+/// See [`FixedScheduleBackoff`] and [`StaticScheduleBackoff`] for variants
+/// that work without an allocator.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::ScheduleBackoff;
+///
+/// // 50ms, 200ms, 1s, 5s, then give up
+/// let backoff = ScheduleBackoff::new([50, 200, 1_000, 5_000]);
+/// assert_eq!(backoff.max_attempts, Some(5));
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone)]
+pub struct ScheduleBackoff {
+    /// Delays in milliseconds, one per retry
+    pub delays_ms: Vec<u64>,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Default for ScheduleBackoff {
+    /// An empty schedule: a single attempt with no retries and no jitter
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl ScheduleBackoff {
+    /// Create a schedule from a list of delays in milliseconds
+    pub fn new(delays_ms: impl Into<Vec<u64>>) -> Self {
+        let delays_ms = delays_ms.into();
+        Self {
+            max_attempts: schedule_attempts(delays_ms.len()),
+            max_elapsed: None,
+            delays_ms,
+            jitter: Jitter::None,
+        }
+    }
+
+    /// Set the maximum number of attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
+    }
+
+    /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
+    pub fn jitter_factor(mut self, factor: f64) -> Self {
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl BackoffStrategy for ScheduleBackoff {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        let base = schedule_base_ms(&self.delays_ms, attempt, self.max_attempts)?;
+        Some(apply_jitter(base, self.jitter, rng))
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let base = schedule_base_ms(&self.delays_ms, attempt, self.max_attempts)?;
+        Some(apply_jitter_duration(base, self.jitter, rng))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+}
+
+/// Fixed-size explicit delay schedule strategy
+///
+/// Behaves like [`ScheduleBackoff`] but stores its delays inline in a
+/// `[u64; N]` array, so it needs no allocator and can live in a `static`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::FixedScheduleBackoff;
+///
+/// static SLA: FixedScheduleBackoff<4> = FixedScheduleBackoff::new([50, 200, 1_000, 5_000]);
+/// assert_eq!(SLA.max_attempts, Some(5));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixedScheduleBackoff<const N: usize> {
+    /// Delays in milliseconds, one per retry
+    pub delays_ms: [u64; N],
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
+}
+
+impl<const N: usize> FixedScheduleBackoff<N> {
+    /// Create a schedule from an array of delays in milliseconds
+    pub const fn new(delays_ms: [u64; N]) -> Self {
+        Self {
+            delays_ms,
+            max_attempts: schedule_attempts(N),
+            max_elapsed: None,
+            jitter: Jitter::None,
+        }
+    }
+
+    /// Set the maximum number of attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
+    }
+
+    /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
+    pub fn jitter_factor(mut self, factor: f64) -> Self {
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl<const N: usize> BackoffStrategy for FixedScheduleBackoff<N> {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        let base = schedule_base_ms(&self.delays_ms, attempt, self.max_attempts)?;
+        Some(apply_jitter(base, self.jitter, rng))
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let base = schedule_base_ms(&self.delays_ms, attempt, self.max_attempts)?;
+        Some(apply_jitter_duration(base, self.jitter, rng))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+}
+
+/// Explicit delay schedule borrowed from a `static` slice
+///
+/// Behaves like [`ScheduleBackoff`] but only borrows its delays, so it is
+/// `Copy`, needs no allocator, and can be stored in a [`BackoffPolicy`].
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::{BackoffPolicy, StaticScheduleBackoff};
+///
+/// static SLA_DELAYS: [u64; 4] = [50, 200, 1_000, 5_000];
+///
+/// let policy = BackoffPolicy::from(StaticScheduleBackoff::new(&SLA_DELAYS));
+/// assert_eq!(policy.max_attempts(), Some(5));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StaticScheduleBackoff {
+    /// Delays in milliseconds, one per retry
+    pub delays_ms: &'static [u64],
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+    /// Jitter mode applied to each delay
+    pub jitter: Jitter,
+}
+
+impl StaticScheduleBackoff {
+    /// Create a schedule from a static slice of delays in milliseconds
+    pub const fn new(delays_ms: &'static [u64]) -> Self {
+        Self {
+            delays_ms,
+            max_attempts: schedule_attempts(delays_ms.len()),
+            max_elapsed: None,
+            jitter: Jitter::None,
+        }
+    }
+
+    /// Set the maximum number of attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
+    }
+
+    /// Set the jitter factor (0.0 = no jitter, 1.0 = full jitter)
+    ///
+    /// Shorthand for `.jitter(Jitter::Proportional(factor))`.
+    pub fn jitter_factor(mut self, factor: f64) -> Self {
+        self.jitter = Jitter::Proportional(factor.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the jitter mode
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl BackoffStrategy for StaticScheduleBackoff {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        let base = schedule_base_ms(self.delays_ms, attempt, self.max_attempts)?;
+        Some(apply_jitter(base, self.jitter, rng))
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        _previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let base = schedule_base_ms(self.delays_ms, attempt, self.max_attempts)?;
+        Some(apply_jitter_duration(base, self.jitter, rng))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+}
+
+/// Decorrelated jitter backoff strategy
+///
+/// Implements the "decorrelated jitter" algorithm popularised by the AWS
+/// Architecture Blog: each delay is drawn uniformly from
+/// `[base_delay, previous_delay * 3]` and capped at `max_delay`.
+/// Because every delay depends on the one before it, concurrent clients drift
+/// apart instead of clustering around the same exponential steps.
+///
+/// The retry loop supplies the previous delay through
+/// [`BackoffStrategy::next_delay_duration`]. Calling [`BackoffStrategy::delay`]
+/// directly treats the previous delay as `base_delay`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::DecorrelatedJitterBackoff;
+///
+/// let backoff = DecorrelatedJitterBackoff::new()
+///     .base_delay_ms(100)
+///     .max_delay_ms(10_000)
+///     .max_attempts(5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DecorrelatedJitterBackoff {
+    /// Base delay (lower bound of every delay)
+    pub base_delay: Duration,
+    /// Maximum delay cap
+    pub max_delay: Duration,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
+    /// Total time budget for all attempts and delays (`None` is unbounded)
+    pub max_elapsed: Option<Duration>,
+}
+
+impl DecorrelatedJitterBackoff {
+    /// Create a new decorrelated jitter backoff builder with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the base delay in milliseconds
+    pub fn base_delay_ms(mut self, ms: u64) -> Self {
+        self.base_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the base delay
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the maximum delay cap in milliseconds
+    pub fn max_delay_ms(mut self, ms: u64) -> Self {
+        self.max_delay = Duration::from_millis(ms);
+        self
+    }
+
+    /// Set the maximum delay cap
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the maximum number of attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
+    }
+}
+
+impl Default for DecorrelatedJitterBackoff {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(10_000),
+            max_attempts: Some(3),
+            max_elapsed: None,
+        }
+    }
+}
+
+impl DecorrelatedJitterBackoff {
+    /// Next delay in fractional milliseconds, or `None` once attempts are exhausted.
+    fn next_ms<R: Rng>(&self, attempt: u32, previous_ms: Option<f64>, rng: &mut R) -> Option<f64> {
+        if attempts_exhausted(attempt, self.max_attempts) {
+            return None;
+        }
+
+        let base = duration_as_ms_f64(self.base_delay);
+        let previous = previous_ms.unwrap_or(base);
+        let upper = (previous * 3.0).max(base);
+        let delay = rng.random_range(base..=upper);
+
+        Some(delay.min(duration_as_ms_f64(self.max_delay)))
+    }
+}
+
+impl BackoffStrategy for DecorrelatedJitterBackoff {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        self.next_delay(attempt, None, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        let previous = previous_delay_ms.map(|ms| ms as f64);
+        self.next_ms(attempt, previous, rng).map(|ms| ms as u64)
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let previous = previous_delay.map(duration_as_ms_f64);
+        self.next_ms(attempt, previous, rng)
+            .map(duration_from_ms_f64)
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        !attempts_exhausted(attempt, self.max_attempts)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+}
+
+/// Backoff policy that can represent any supported strategy.
+///
+/// The enum form makes it possible to store heterogeneous strategies in a
+/// registry or configuration without heap allocation or dynamic dispatch.
+#[derive(Debug, Clone, Copy)]
+pub enum BackoffPolicy {
+    /// Exponential backoff policy
+    Exponential(ExponentialBackoff),
+    /// Constant backoff policy
+    Constant(ConstantBackoff),
+    /// Fibonacci backoff policy
+    Fibonacci(FibonacciBackoff),
+    /// Decorrelated jitter backoff policy
+    Decorrelated(DecorrelatedJitterBackoff),
+    /// Linear backoff policy
+    Linear(LinearBackoff),
+    /// Polynomial backoff policy
+    Polynomial(PolynomialBackoff),
+    /// Explicit delay schedule policy
+    Schedule(StaticScheduleBackoff),
+}
+
+impl BackoffPolicy {
+    /// Return the maximum retry attempts for the wrapped strategy.
+    pub fn max_attempts(&self) -> Option<u32> {
+        match self {
+            BackoffPolicy::Exponential(policy) => policy.max_attempts,
+            BackoffPolicy::Constant(policy) => policy.max_attempts,
+            BackoffPolicy::Fibonacci(policy) => policy.max_attempts,
+            BackoffPolicy::Decorrelated(policy) => policy.max_attempts,
+            BackoffPolicy::Linear(policy) => policy.max_attempts,
+            BackoffPolicy::Polynomial(policy) => policy.max_attempts,
+            BackoffPolicy::Schedule(policy) => policy.max_attempts,
+        }
+    }
+}
+
+impl BackoffStrategy for BackoffPolicy {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        match self {
+            BackoffPolicy::Exponential(policy) => policy.delay(attempt, rng),
+            BackoffPolicy::Constant(policy) => policy.delay(attempt, rng),
+            BackoffPolicy::Fibonacci(policy) => policy.delay(attempt, rng),
+            BackoffPolicy::Decorrelated(policy) => policy.delay(attempt, rng),
+            BackoffPolicy::Linear(policy) => policy.delay(attempt, rng),
+            BackoffPolicy::Polynomial(policy) => policy.delay(attempt, rng),
+            BackoffPolicy::Schedule(policy) => policy.delay(attempt, rng),
+        }
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        match self {
+            BackoffPolicy::Exponential(policy) => {
+                policy.next_delay(attempt, previous_delay_ms, rng)
+            }
+            BackoffPolicy::Constant(policy) => policy.next_delay(attempt, previous_delay_ms, rng),
+            BackoffPolicy::Fibonacci(policy) => policy.next_delay(attempt, previous_delay_ms, rng),
+            BackoffPolicy::Decorrelated(policy) => {
+                policy.next_delay(attempt, previous_delay_ms, rng)
+            }
+            BackoffPolicy::Linear(policy) => policy.next_delay(attempt, previous_delay_ms, rng),
+            BackoffPolicy::Polynomial(policy) => policy.next_delay(attempt, previous_delay_ms, rng),
+            BackoffPolicy::Schedule(policy) => policy.next_delay(attempt, previous_delay_ms, rng),
+        }
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        match self {
+            BackoffPolicy::Exponential(policy) => {
+                policy.next_delay_duration(attempt, previous_delay, rng)
+            }
+            BackoffPolicy::Constant(policy) => {
+                policy.next_delay_duration(attempt, previous_delay, rng)
+            }
+            BackoffPolicy::Fibonacci(policy) => {
+                policy.next_delay_duration(attempt, previous_delay, rng)
+            }
+            BackoffPolicy::Decorrelated(policy) => {
+                policy.next_delay_duration(attempt, previous_delay, rng)
+            }
+            BackoffPolicy::Linear(policy) => {
+                policy.next_delay_duration(attempt, previous_delay, rng)
+            }
+            BackoffPolicy::Polynomial(policy) => {
+                policy.next_delay_duration(attempt, previous_delay, rng)
+            }
+            BackoffPolicy::Schedule(policy) => {
+                policy.next_delay_duration(attempt, previous_delay, rng)
+            }
+        }
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        match self {
+            BackoffPolicy::Exponential(policy) => policy.should_retry(attempt),
+            BackoffPolicy::Constant(policy) => policy.should_retry(attempt),
+            BackoffPolicy::Fibonacci(policy) => policy.should_retry(attempt),
+            BackoffPolicy::Decorrelated(policy) => policy.should_retry(attempt),
+            BackoffPolicy::Linear(policy) => policy.should_retry(attempt),
+            BackoffPolicy::Polynomial(policy) => policy.should_retry(attempt),
+            BackoffPolicy::Schedule(policy) => policy.should_retry(attempt),
+        }
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        match self {
+            BackoffPolicy::Exponential(policy) => policy.max_attempts(),
+            BackoffPolicy::Constant(policy) => policy.max_attempts(),
+            BackoffPolicy::Fibonacci(policy) => policy.max_attempts(),
+            BackoffPolicy::Decorrelated(policy) => policy.max_attempts(),
+            BackoffPolicy::Linear(policy) => policy.max_attempts(),
+            BackoffPolicy::Polynomial(policy) => policy.max_attempts(),
+            BackoffPolicy::Schedule(policy) => policy.max_attempts(),
+        }
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        match self {
+            BackoffPolicy::Exponential(policy) => policy.max_elapsed(),
+            BackoffPolicy::Constant(policy) => policy.max_elapsed(),
+            BackoffPolicy::Fibonacci(policy) => policy.max_elapsed(),
+            BackoffPolicy::Decorrelated(policy) => policy.max_elapsed(),
+            BackoffPolicy::Linear(policy) => policy.max_elapsed(),
+            BackoffPolicy::Polynomial(policy) => policy.max_elapsed(),
+            BackoffPolicy::Schedule(policy) => policy.max_elapsed(),
+        }
+    }
+}
+
+impl From<ExponentialBackoff> for BackoffPolicy {
+    fn from(value: ExponentialBackoff) -> Self {
+        BackoffPolicy::Exponential(value)
+    }
+}
+
+impl From<ConstantBackoff> for BackoffPolicy {
+    fn from(value: ConstantBackoff) -> Self {
+        BackoffPolicy::Constant(value)
+    }
+}
+
+impl From<FibonacciBackoff> for BackoffPolicy {
+    fn from(value: FibonacciBackoff) -> Self {
+        BackoffPolicy::Fibonacci(value)
+    }
+}
+
+impl From<DecorrelatedJitterBackoff> for BackoffPolicy {
+    fn from(value: DecorrelatedJitterBackoff) -> Self {
+        BackoffPolicy::Decorrelated(value)
+    }
+}
+
+impl From<LinearBackoff> for BackoffPolicy {
+    fn from(value: LinearBackoff) -> Self {
+        BackoffPolicy::Linear(value)
+    }
+}
+
+impl From<PolynomialBackoff> for BackoffPolicy {
+    fn from(value: PolynomialBackoff) -> Self {
+        BackoffPolicy::Polynomial(value)
+    }
+}
+
+impl From<StaticScheduleBackoff> for BackoffPolicy {
+    fn from(value: StaticScheduleBackoff) -> Self {
+        BackoffPolicy::Schedule(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_exponential_backoff_builder() {
+        let backoff = ExponentialBackoff::new()
+            .base_delay_ms(200)
+            .multiplier(3.0)
+            .max_delay_ms(5000)
+            .max_attempts(5)
+            .jitter_factor(0.5);
+
+        assert_eq!(backoff.base_delay, Duration::from_millis(200));
+        assert_eq!(backoff.multiplier, 3.0);
+        assert_eq!(backoff.max_delay, Duration::from_millis(5000));
+        assert_eq!(backoff.max_attempts, Some(5));
+        assert_eq!(backoff.jitter, Jitter::Proportional(0.5));
+    }
+
+    #[test]
+    fn test_exponential_delays() {
+        let backoff = ExponentialBackoff::new()
+            .base_delay_ms(100)
+            .multiplier(2.0)
+            .jitter_factor(0.0); // No jitter for predictable testing
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(backoff.delay(1, &mut rng), Some(100));
+        assert_eq!(backoff.delay(2, &mut rng), Some(200));
+        assert_eq!(backoff.delay(3, &mut rng), None); // Exceeds max_attempts (default 3)
+    }
+
+    #[test]
+    fn test_constant_backoff() {
+        let backoff = ConstantBackoff::new()
+            .delay_ms(500)
+            .max_attempts(4)
+            .jitter_factor(0.0);
 
         let mut rng = StdRng::seed_from_u64(42);
 
@@ -481,6 +1747,9 @@ mod tests {
         assert_eq!(fibonacci(5), 5);
         assert_eq!(fibonacci(6), 8);
         assert_eq!(fibonacci(7), 13);
+        assert_eq!(fibonacci(93), 12_200_160_415_121_876_738);
+        assert_eq!(fibonacci(94), u64::MAX);
+        assert_eq!(fibonacci(u32::MAX), u64::MAX);
     }
 
     #[test]
@@ -499,6 +1768,100 @@ mod tests {
         assert_eq!(backoff.delay(5, &mut rng), None); // Exceeds max_attempts
     }
 
+    #[test]
+    fn test_linear_backoff() {
+        let backoff = LinearBackoff::new()
+            .base_delay_ms(100)
+            .step_ms(250)
+            .max_delay_ms(700)
+            .max_attempts(5)
+            .jitter_factor(0.0);
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(backoff.delay(1, &mut rng), Some(100)); // 100 + 0 * 250
+        assert_eq!(backoff.delay(2, &mut rng), Some(350)); // 100 + 1 * 250
+        assert_eq!(backoff.delay(3, &mut rng), Some(600)); // 100 + 2 * 250
+        assert_eq!(backoff.delay(4, &mut rng), Some(700)); // Capped at max_delay_ms
+        assert_eq!(backoff.delay(5, &mut rng), None); // Exceeds max_attempts
+    }
+
+    #[test]
+    fn test_polynomial_backoff() {
+        let backoff = PolynomialBackoff::new()
+            .base_delay_ms(10)
+            .exponent(3)
+            .max_delay_ms(500)
+            .max_attempts(6)
+            .jitter(Jitter::None);
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(backoff.delay(1, &mut rng), Some(10)); // 10 * 1^3
+        assert_eq!(backoff.delay(2, &mut rng), Some(80)); // 10 * 2^3
+        assert_eq!(backoff.delay(3, &mut rng), Some(270)); // 10 * 3^3
+        assert_eq!(backoff.delay(4, &mut rng), Some(500)); // Capped at max_delay_ms
+        assert_eq!(backoff.delay(6, &mut rng), None); // Exceeds max_attempts
+    }
+
+    #[test]
+    fn test_schedule_backoff() {
+        let backoff = ScheduleBackoff::new([50, 200, 1_000, 5_000]);
+        assert_eq!(backoff.max_attempts, Some(5));
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(backoff.delay(1, &mut rng), Some(50));
+        assert_eq!(backoff.delay(2, &mut rng), Some(200));
+        assert_eq!(backoff.delay(3, &mut rng), Some(1_000));
+        assert_eq!(backoff.delay(4, &mut rng), Some(5_000));
+        assert_eq!(backoff.delay(5, &mut rng), None); // Schedule exhausted
+
+        // Extra attempts repeat the final delay
+        let extended = backoff.clone().max_attempts(7);
+        assert_eq!(extended.delay(6, &mut rng), Some(5_000));
+        assert_eq!(extended.delay(7, &mut rng), None);
+
+        // An empty schedule never retries
+        assert_eq!(ScheduleBackoff::new(Vec::new()).delay(1, &mut rng), None);
+    }
+
+    #[test]
+    fn test_schedule_backoff_default() {
+        let backoff = ScheduleBackoff::default();
+        assert!(backoff.delays_ms.is_empty());
+        assert_eq!(backoff.max_attempts, Some(1));
+        assert_eq!(backoff.max_elapsed, None);
+        assert!(matches!(backoff.jitter, Jitter::None));
+    }
+
+    #[test]
+    fn test_fixed_schedule_backoff() {
+        const SCHEDULE: FixedScheduleBackoff<3> = FixedScheduleBackoff::new([10, 20, 40]);
+        assert_eq!(SCHEDULE.max_attempts, Some(4));
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(SCHEDULE.delay(1, &mut rng), Some(10));
+        assert_eq!(SCHEDULE.delay(3, &mut rng), Some(40));
+        assert_eq!(SCHEDULE.delay(4, &mut rng), None);
+
+        let jittered = SCHEDULE.jitter(Jitter::Equal);
+        let delay = jittered.delay(2, &mut rng).unwrap();
+        assert!((10..=20).contains(&delay), "got {delay}");
+    }
+
+    #[test]
+    fn test_schedule_policy() {
+        static DELAYS: [u64; 2] = [100, 300];
+        let policy = BackoffPolicy::from(StaticScheduleBackoff::new(&DELAYS));
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(policy.max_attempts(), Some(3));
+        assert_eq!(policy.delay(2, &mut rng), Some(300));
+        assert_eq!(policy.delay(3, &mut rng), None);
+    }
+
     #[test]
     fn test_jitter_application() {
         let backoff = ConstantBackoff::new().delay_ms(1000).jitter_factor(1.0); // Full jitter
@@ -513,4 +1876,204 @@ mod tests {
         // All delays should be <= base delay
         assert!(delays.iter().all(|&d| d <= 1000));
     }
+
+    #[test]
+    fn test_jitter_modes_per_strategy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let exponential = ExponentialBackoff::new()
+            .base_delay_ms(1000)
+            .max_attempts(5)
+            .jitter(Jitter::Equal);
+        for attempt in 1..5 {
+            let ceiling = 1000 * (1 << (attempt - 1));
+            let delay = exponential.delay(attempt, &mut rng).unwrap();
+            assert!((ceiling / 2..=ceiling).contains(&delay), "got {delay}");
+        }
+
+        let constant = ConstantBackoff::new()
+            .delay_ms(100)
+            .jitter(Jitter::Bounded {
+                min_ms: 5,
+                max_ms: 20,
+            });
+        let delay = constant.delay(1, &mut rng).unwrap();
+        assert!((105..=120).contains(&delay), "got {delay}");
+
+        let fibonacci = FibonacciBackoff::new()
+            .base_delay_ms(100)
+            .jitter(Jitter::None);
+        assert_eq!(fibonacci.delay(4, &mut rng), Some(300));
+    }
+
+    #[test]
+    fn test_decorrelated_backoff_bounds() {
+        let backoff = DecorrelatedJitterBackoff::new()
+            .base_delay_ms(100)
+            .max_delay_ms(1_000)
+            .max_attempts(10);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut previous = None;
+
+        for attempt in 1..10 {
+            let delay = backoff
+                .next_delay(attempt, previous, &mut rng)
+                .expect("within max_attempts");
+            let upper = previous.unwrap_or(100).saturating_mul(3).min(1_000);
+            assert!(
+                (100..=upper).contains(&delay),
+                "attempt {attempt}: delay {delay} outside [100, {upper}]"
+            );
+            previous = Some(delay);
+        }
+
+        assert_eq!(backoff.next_delay(10, previous, &mut rng), None);
+    }
+
+    #[test]
+    fn test_decorrelated_backoff_respects_cap() {
+        let backoff = DecorrelatedJitterBackoff::new()
+            .base_delay_ms(100)
+            .max_delay_ms(250)
+            .max_attempts(5);
+
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..50 {
+            let delay = backoff.next_delay(2, Some(10_000), &mut rng).unwrap();
+            assert!((100..=250).contains(&delay));
+        }
+    }
+
+    #[test]
+    fn test_sub_millisecond_delays() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let backoff = ExponentialBackoff::new()
+            .base_delay(Duration::from_micros(100))
+            .max_delay(Duration::from_micros(300))
+            .max_attempts(5)
+            .jitter(Jitter::None);
+        assert_eq!(
+            backoff.delay_duration(1, &mut rng),
+            Some(Duration::from_micros(100))
+        );
+        assert_eq!(
+            backoff.delay_duration(2, &mut rng),
+            Some(Duration::from_micros(200))
+        );
+        assert_eq!(
+            backoff.delay_duration(4, &mut rng),
+            Some(Duration::from_micros(300))
+        );
+        // The millisecond API truncates
+        assert_eq!(backoff.delay(1, &mut rng), Some(0));
+
+        let constant = ConstantBackoff::new().interval(Duration::from_micros(250));
+        assert_eq!(
+            constant.delay_duration(1, &mut rng),
+            Some(Duration::from_micros(250))
+        );
+
+        let linear = LinearBackoff::new()
+            .base_delay(Duration::from_micros(50))
+            .step(Duration::from_micros(25))
+            .jitter(Jitter::None);
+        assert_eq!(
+            linear.delay_duration(2, &mut rng),
+            Some(Duration::from_micros(75))
+        );
+
+        let decorrelated = DecorrelatedJitterBackoff::new()
+            .base_delay(Duration::from_micros(100))
+            .max_delay(Duration::from_millis(1));
+        let delay = decorrelated
+            .next_delay_duration(2, Some(Duration::from_micros(200)), &mut rng)
+            .unwrap();
+        assert!((Duration::from_micros(100)..=Duration::from_micros(600)).contains(&delay));
+    }
+
+    #[test]
+    fn test_ms_and_duration_builders_set_the_same_field() {
+        let backoff = ExponentialBackoff::new().base_delay(Duration::from_micros(1_500));
+        assert_eq!(backoff.base_delay, Duration::from_micros(1_500));
+
+        // The millisecond setter replaces the sub-millisecond part
+        let backoff = backoff.base_delay_ms(2);
+        assert_eq!(backoff.base_delay, Duration::from_millis(2));
+
+        let linear = LinearBackoff::new().step(Duration::from_micros(250));
+        assert_eq!(linear.step, Duration::from_micros(250));
+        assert_eq!(linear.step_ms(3).step, Duration::from_millis(3));
+
+        let constant = ConstantBackoff::new().delay_ms(250);
+        assert_eq!(constant.interval, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_millisecond_strategies_convert_to_duration() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let schedule = FixedScheduleBackoff::new([5, 10]);
+
+        assert_eq!(
+            schedule.delay_duration(2, &mut rng),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(schedule.delay_duration(3, &mut rng), None);
+
+        // Jittered schedule delays aren't truncated to whole milliseconds
+        let jittered = schedule.jitter(Jitter::Full);
+        let delays: [Duration; 8] =
+            core::array::from_fn(|_| jittered.delay_duration(2, &mut rng).unwrap());
+        assert!(delays
+            .iter()
+            .all(|&delay| delay <= Duration::from_millis(10)));
+        assert!(delays
+            .iter()
+            .any(|delay| delay.subsec_nanos() % 1_000_000 != 0));
+    }
+
+    #[test]
+    fn test_unlimited_attempts() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let backoff = ExponentialBackoff::new()
+            .max_delay_ms(1_000)
+            .jitter(Jitter::None)
+            .unlimited();
+
+        assert_eq!(backoff.max_attempts, None);
+        assert!(backoff.should_retry(u32::MAX - 1));
+        assert_eq!(backoff.delay(300, &mut rng), Some(1_000));
+        assert_eq!(backoff.delay(u32::MAX, &mut rng), Some(1_000));
+
+        let limited = backoff.max_attempts(1_000);
+        assert_eq!(limited.delay(999, &mut rng), Some(1_000));
+        assert_eq!(limited.delay(1_000, &mut rng), None);
+    }
+
+    #[test]
+    fn test_large_attempt_numbers_stay_capped() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let fibonacci = FibonacciBackoff::new()
+            .max_delay_ms(5_000)
+            .jitter(Jitter::None)
+            .unlimited();
+        assert_eq!(fibonacci.delay(100_000, &mut rng), Some(5_000));
+
+        let polynomial = PolynomialBackoff::new()
+            .exponent(3)
+            .max_delay_ms(5_000)
+            .jitter(Jitter::None)
+            .unlimited();
+        assert_eq!(polynomial.delay(u32::MAX, &mut rng), Some(5_000));
+
+        // A zero base delay stays zero even when the growth factor overflows
+        let zero = ExponentialBackoff::new()
+            .base_delay_ms(0)
+            .jitter(Jitter::None)
+            .unlimited();
+        assert_eq!(zero.delay(5_000, &mut rng), Some(0));
+    }
 }
@@ -0,0 +1,187 @@
+//! Retry budgets shared across calls (requires `std` or `alloc` feature)
+//!
+//! Per-call attempt limits don't bound the total load during an upstream
+//! outage: every caller still retries up to its limit. A [`RetryBudget`] is a
+//! token bucket shared by all calls to the same dependency, in the style of
+//! Finagle retry budgets and gRPC retry throttling. Each successful call
+//! deposits a fraction of a token and each retry withdraws a whole one, so
+//! retries stay capped at a percentage of traffic. Once the bucket is empty
+//! the retry loop fails fast with
+//! [`RetryErrorKind::BudgetExhausted`](crate::retry::RetryErrorKind::BudgetExhausted)
+//! instead of scheduling another attempt.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::budget::RetryBudget;
+//! use chrono_machines::{ConstantBackoff, Retryable, RetryErrorKind};
+//!
+//! // Two retries in reserve, then one retry per ten successful calls
+//! let budget = RetryBudget::new(2, 0.1);
+//!
+//! let error = (|| Err::<(), _>("upstream unavailable"))
+//!     .retry(ConstantBackoff::new().delay_ms(1).max_attempts(10))
+//!     .budget(budget.clone())
+//!     .call()
+//!     .unwrap_err();
+//!
+//! assert_eq!(error.kind(), RetryErrorKind::BudgetExhausted);
+//! assert_eq!(error.attempts(), 3);
+//! ```
+
+use core::fmt;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+/// Fixed-point scale: the bucket counts thousandths of a token
+const SCALE: u32 = 1000;
+
+struct Inner {
+    tokens: AtomicU32,
+    max_tokens: u32,
+    deposit: u32,
+}
+
+/// Cloneable token bucket limiting retries across calls
+///
+/// All clones share the same bucket. The bucket starts full so a cold client
+/// can retry right away, and never holds more than `max_tokens`.
+#[derive(Clone)]
+pub struct RetryBudget {
+    inner: Arc<Inner>,
+}
+
+impl RetryBudget {
+    /// Create a full budget of `max_tokens` retries, refilled by `ratio` of a
+    /// token per successful call
+    ///
+    /// `ratio` is the share of traffic allowed to be retries once the reserve
+    /// is spent, e.g. `0.1` allows one retry per ten successes. It is clamped
+    /// to `0.0..=1.0`, with NaN treated as `0.0`.
+    pub fn new(max_tokens: u32, ratio: f64) -> Self {
+        let ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+        let max_tokens = max_tokens.saturating_mul(SCALE);
+
+        Self {
+            inner: Arc::new(Inner {
+                tokens: AtomicU32::new(max_tokens),
+                max_tokens,
+                // Round to the nearest thousandth (`f64::round` needs std)
+                deposit: (ratio * SCALE as f64 + 0.5) as u32,
+            }),
+        }
+    }
+
+    /// Record a successful call, earning back part of a token
+    pub fn deposit(&self) {
+        let Inner {
+            tokens,
+            max_tokens,
+            deposit,
+        } = &*self.inner;
+        let _ = tokens.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+            Some(current.saturating_add(*deposit).min(*max_tokens))
+        });
+    }
+
+    /// Take one token for a retry
+    ///
+    /// Returns `false`, leaving the bucket untouched, if less than a whole
+    /// token is left.
+    pub fn try_withdraw(&self) -> bool {
+        self.inner
+            .tokens
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                current.checked_sub(SCALE)
+            })
+            .is_ok()
+    }
+
+    /// Tokens currently in the bucket, including fractions
+    pub fn available(&self) -> f64 {
+        self.inner.tokens.load(Ordering::Relaxed) as f64 / SCALE as f64
+    }
+}
+
+impl Default for RetryBudget {
+    /// Ten retries in reserve, then one retry per ten successful calls
+    fn default() -> Self {
+        Self::new(10, 0.1)
+    }
+}
+
+impl fmt::Debug for RetryBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryBudget")
+            .field("available", &self.available())
+            .field("max_tokens", &(self.inner.max_tokens / SCALE))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_full_and_empties() {
+        let budget = RetryBudget::new(2, 0.5);
+        assert_eq!(budget.available(), 2.0);
+
+        assert!(budget.try_withdraw());
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+        assert_eq!(budget.available(), 0.0);
+    }
+
+    #[test]
+    fn test_successes_refill_in_fractions() {
+        let budget = RetryBudget::new(1, 0.25);
+        assert!(budget.try_withdraw());
+
+        for _ in 0..3 {
+            budget.deposit();
+            assert!(!budget.try_withdraw());
+        }
+        budget.deposit();
+        assert!(budget.try_withdraw());
+    }
+
+    #[test]
+    fn test_deposits_capped_at_max_tokens() {
+        let budget = RetryBudget::new(1, 1.0);
+        for _ in 0..5 {
+            budget.deposit();
+        }
+        assert_eq!(budget.available(), 1.0);
+    }
+
+    #[test]
+    fn test_ratio_clamped() {
+        let budget = RetryBudget::new(0, f64::NAN);
+        budget.deposit();
+        assert_eq!(budget.available(), 0.0);
+
+        let budget = RetryBudget::new(5, 3.0);
+        assert!((0..5).all(|_| budget.try_withdraw()));
+        budget.deposit();
+        assert_eq!(budget.available(), 1.0);
+    }
+
+    #[test]
+    fn test_clones_share_the_bucket() {
+        let budget = RetryBudget::new(1, 0.1);
+        let handle = budget.clone();
+
+        assert!(handle.try_withdraw());
+        assert!(!budget.try_withdraw());
+    }
+}
@@ -0,0 +1,312 @@
+//! Cancellation of in-flight retry loops (requires `std` feature)
+//!
+//! A [`CancellationToken`] is a cloneable handle shared between the code
+//! running a retry loop and whoever may need to abort it, such as a shutdown
+//! handler. Pass it to
+//! [`RetryBuilder::cancel_on`](crate::retry::RetryBuilder::cancel_on): once
+//! cancelled, the pending sleep returns early, no further attempt is made and
+//! the loop fails with [`RetryErrorKind::Cancelled`](crate::retry::RetryErrorKind::Cancelled).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::cancel::CancellationToken;
+//! use chrono_machines::{ConstantBackoff, Retryable, RetryErrorKind};
+//!
+//! let token = CancellationToken::new();
+//! let shutdown = token.clone();
+//! let worker = std::thread::spawn(move || {
+//!     (|| Err::<(), _>("upstream unavailable"))
+//!         .retry(ConstantBackoff::new().delay_ms(60_000).max_attempts(10))
+//!         .cancel_on(token)
+//!         .call()
+//! });
+//!
+//! // Returns promptly instead of waiting out the minute-long delay
+//! shutdown.cancel();
+//! let error = worker.join().unwrap().unwrap_err();
+//! assert_eq!(error.kind(), RetryErrorKind::Cancelled);
+//! ```
+
+use crate::sleep::Sleeper;
+use core::fmt;
+use core::future::Future;
+use core::pin::{pin, Pin};
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::Instant;
+
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Wakers>,
+    condvar: Condvar,
+}
+
+/// Wakers of pending [`Cancelled`] futures, keyed by registration id
+#[derive(Default)]
+struct Wakers {
+    next_id: u64,
+    entries: Vec<(u64, Waker)>,
+}
+
+/// Cloneable handle used to cancel retry loops from another thread or task
+///
+/// All clones share the same state: cancelling one cancels them all.
+/// Cancellation is permanent.
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                cancelled: AtomicBool::new(false),
+                wakers: Mutex::new(Wakers::default()),
+                condvar: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Cancel the token, waking every thread and task waiting on it
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        let mut wakers = self.lock_wakers();
+        for (_, waker) in wakers.entries.drain(..) {
+            waker.wake();
+        }
+        drop(wakers);
+        self.inner.condvar.notify_all();
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Block the current thread for `duration` or until cancelled
+    ///
+    /// Returns `true` if the token was cancelled.
+    pub fn wait_timeout(&self, duration: Duration) -> bool {
+        let deadline = Instant::now().checked_add(duration);
+        let mut guard = self.lock_wakers();
+        while !self.is_cancelled() {
+            let remaining = match deadline {
+                Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+                // Too far in the future to represent: wait in large steps
+                None => Duration::from_secs(u32::MAX as u64),
+            };
+            if remaining.is_zero() {
+                return false;
+            }
+            guard = match self.inner.condvar.wait_timeout(guard, remaining) {
+                Ok((guard, _)) => guard,
+                Err(poisoned) => poisoned.into_inner().0,
+            };
+        }
+        true
+    }
+
+    /// Future that completes once the token is cancelled
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            registration: None,
+        }
+    }
+
+    /// Run `future` to completion unless the token is cancelled first
+    ///
+    /// Returns `None` if cancellation won the race; `future` is dropped.
+    pub async fn run_until_cancelled<F: Future>(&self, future: F) -> Option<F::Output> {
+        let mut future = pin!(future);
+        let mut cancelled = pin!(self.cancelled());
+        core::future::poll_fn(|cx| {
+            if cancelled.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(None);
+            }
+            future.as_mut().poll(cx).map(Some)
+        })
+        .await
+    }
+
+    /// A [`Sleeper`] whose sleeps end early when this token is cancelled
+    pub fn sleeper(&self) -> InterruptibleSleeper {
+        InterruptibleSleeper {
+            token: self.clone(),
+        }
+    }
+
+    fn lock_wakers(&self) -> MutexGuard<'_, Wakers> {
+        // Wakers stay valid even if a holder panicked
+        self.inner
+            .wakers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled`]
+///
+/// Registers its waker with the token while pending and removes it again
+/// when dropped, so long-lived tokens don't accumulate stale wakers.
+#[derive(Debug)]
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    registration: Option<u64>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let this = self.get_mut();
+        let mut wakers = this.token.lock_wakers();
+        // Re-check under the lock so a concurrent cancel can't be missed
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let registered = this
+            .registration
+            .and_then(|id| wakers.entries.iter_mut().find(|(entry, _)| *entry == id));
+        match registered {
+            Some((_, waker)) => {
+                if !waker.will_wake(cx.waker()) {
+                    waker.clone_from(cx.waker());
+                }
+            }
+            None => {
+                let id = wakers.next_id;
+                wakers.next_id += 1;
+                wakers.entries.push((id, cx.waker().clone()));
+                this.registration = Some(id);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(id) = self.registration {
+            let mut wakers = self.token.lock_wakers();
+            if let Some(index) = wakers.entries.iter().position(|(entry, _)| *entry == id) {
+                wakers.entries.swap_remove(index);
+            }
+        }
+    }
+}
+
+/// Blocking sleeper that returns early when its token is cancelled
+///
+/// [`RetryBuilder::call`](crate::retry::RetryBuilder::call) uses one
+/// automatically when a token is set with
+/// [`cancel_on`](crate::retry::RetryBuilder::cancel_on); pass
+/// [`CancellationToken::sleeper`] to
+/// [`call_with_sleeper`](crate::retry::RetryBuilder::call_with_sleeper) to get
+/// the same behavior there.
+#[derive(Debug, Clone)]
+pub struct InterruptibleSleeper {
+    token: CancellationToken,
+}
+
+impl Sleeper for InterruptibleSleeper {
+    fn sleep_ms(&self, ms: u64) {
+        self.token.wait_timeout(Duration::from_millis(ms));
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.token.wait_timeout(duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_interrupts_wait() {
+        let token = CancellationToken::new();
+        let waiter = token.clone();
+        let handle = std::thread::spawn(move || {
+            let start = Instant::now();
+            let cancelled = waiter.wait_timeout(Duration::from_secs(60));
+            (cancelled, start.elapsed())
+        });
+
+        std::thread::sleep(Duration::from_millis(20));
+        token.cancel();
+        let (cancelled, waited) = handle.join().unwrap();
+
+        assert!(cancelled);
+        assert!(waited < Duration::from_secs(10));
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_wait_times_out_without_cancel() {
+        let token = CancellationToken::new();
+
+        assert!(!token.wait_timeout(Duration::from_millis(5)));
+        token.cancel();
+        assert!(token.wait_timeout(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_cancelled_future_unregisters_its_waker() {
+        let token = CancellationToken::new();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        {
+            let mut first = pin!(token.cancelled());
+            let mut second = pin!(token.cancelled());
+            for _ in 0..3 {
+                assert!(first.as_mut().poll(&mut cx).is_pending());
+                assert!(second.as_mut().poll(&mut cx).is_pending());
+            }
+            assert_eq!(token.lock_wakers().entries.len(), 2);
+        }
+        assert!(token.lock_wakers().entries.is_empty());
+
+        let mut cancelled = pin!(token.cancelled());
+        assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+        token.cancel();
+        assert!(cancelled.as_mut().poll(&mut cx).is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_run_until_cancelled() {
+        let token = CancellationToken::new();
+        assert_eq!(token.run_until_cancelled(async { 7 }).await, Some(7));
+
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            canceller.cancel();
+        });
+        let slow = tokio::time::sleep(Duration::from_secs(60));
+        assert_eq!(token.run_until_cancelled(slow).await, None);
+    }
+}
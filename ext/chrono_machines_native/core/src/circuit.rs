@@ -0,0 +1,484 @@
+//! Circuit breaking for retry loops (requires `std` feature)
+//!
+//! A [`CircuitBreaker`] watches the outcome of calls to a dependency and
+//! stops sending it traffic once too many of them fail. It moves between
+//! three states:
+//!
+//! - **Closed**: calls go through and their outcomes fill a sliding window.
+//!   Once the window holds enough calls and the share of failures reaches
+//!   the threshold, the circuit opens.
+//! - **Open**: calls are rejected until a cool-down passes. The cool-down is
+//!   taken from a [`BackoffStrategy`], so it can grow each time the circuit
+//!   re-opens without closing in between.
+//! - **Half-open**: a limited number of probe calls go through. If they all
+//!   succeed the circuit closes; any failure opens it again.
+//!
+//! Attach it with
+//! [`RetryBuilder::circuit_breaker`](crate::retry::RetryBuilder::circuit_breaker):
+//! the breaker is consulted before every attempt and told how it went. An
+//! attempt the breaker rejects is not made, and the loop fails with
+//! [`RetryErrorKind::CircuitOpen`](crate::retry::RetryErrorKind::CircuitOpen).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::circuit::{CircuitBreaker, CircuitState};
+//! use chrono_machines::{ConstantBackoff, ExponentialBackoff, Retryable, RetryErrorKind};
+//!
+//! // Open once half of the last 10 calls failed (after at least 3 calls),
+//! // then cool down for 30s, 1m, 2m...
+//! let breaker = CircuitBreaker::new(
+//!     ExponentialBackoff::new().base_delay_ms(30_000).jitter_factor(0.0).unlimited(),
+//! )
+//! .window(10)
+//! .min_calls(3)
+//! .failure_rate(0.5);
+//!
+//! let error = (|| Err::<(), _>("upstream unavailable"))
+//!     .retry(ConstantBackoff::new().delay_ms(1).max_attempts(5))
+//!     .circuit_breaker(breaker.clone())
+//!     .call()
+//!     .unwrap_err();
+//!
+//! assert_eq!(error.kind(), RetryErrorKind::CircuitOpen);
+//! assert_eq!(error.attempts(), 3);
+//! assert_eq!(breaker.state(), CircuitState::Open);
+//! ```
+
+use crate::backoff::BackoffStrategy;
+use crate::clock::{Clock, StdClock};
+use core::fmt;
+use core::time::Duration;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Type alias for the cool-down schedule, by number of consecutive trips
+type CoolDown = dyn Fn(u32, Option<Duration>) -> Option<Duration> + Send + Sync;
+
+/// Observable state of a [`CircuitBreaker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls go through and are counted.
+    Closed,
+    /// Calls are rejected until the cool-down has passed.
+    Open,
+    /// A limited number of probe calls go through.
+    HalfOpen,
+}
+
+enum Phase {
+    Closed,
+    Open { until: Duration },
+    HalfOpen { admitted: u32, succeeded: u32 },
+}
+
+struct State {
+    phase: Phase,
+    /// Outcomes of the latest calls while closed, `true` for failures
+    window: VecDeque<bool>,
+    failures: u32,
+    /// Times the circuit opened since it last closed
+    trips: u32,
+    cool_down: Option<Duration>,
+}
+
+impl State {
+    /// Move an open circuit whose cool-down has passed to half-open
+    fn refresh(&mut self, now: Duration) {
+        if let Phase::Open { until } = self.phase
+            && now >= until
+        {
+            self.phase = Phase::HalfOpen {
+                admitted: 0,
+                succeeded: 0,
+            };
+        }
+    }
+
+    fn clear_window(&mut self) {
+        self.window.clear();
+        self.failures = 0;
+    }
+}
+
+/// Cloneable circuit breaker shared by the calls to one dependency
+///
+/// All clones share the same circuit. Configure the breaker before cloning
+/// it: clones keep the configuration they were made with.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    failure_rate: f64,
+    window: u32,
+    min_calls: u32,
+    probes: u32,
+    cool_down: Arc<CoolDown>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    state: Arc<Mutex<State>>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker that cools down on `cool_down`'s schedule
+    ///
+    /// The n-th time the circuit opens without closing in between, it stays
+    /// open for the delay `cool_down` gives attempt n. Once the strategy
+    /// stops producing delays, the last cool-down is reused.
+    ///
+    /// # Default values
+    ///
+    /// - `failure_rate`: 0.5
+    /// - `window`: 20
+    /// - `min_calls`: 10
+    /// - `probes`: 1
+    pub fn new<B>(cool_down: B) -> Self
+    where
+        B: BackoffStrategy + Send + Sync + 'static,
+    {
+        Self {
+            failure_rate: 0.5,
+            window: 20,
+            min_calls: 10,
+            probes: 1,
+            cool_down: Arc::new(move |trips, previous| {
+                let mut rng: StdRng = rand::make_rng();
+                cool_down.next_delay_duration(trips, previous, &mut rng)
+            }),
+            clock: Arc::new(StdClock::new()),
+            state: Arc::new(Mutex::new(State {
+                phase: Phase::Closed,
+                window: VecDeque::new(),
+                failures: 0,
+                trips: 0,
+                cool_down: None,
+            })),
+        }
+    }
+
+    /// Share of failed calls in the window that opens the circuit
+    ///
+    /// Clamped to `0.0..=1.0`, with NaN treated as `1.0`.
+    pub fn failure_rate(mut self, rate: f64) -> Self {
+        self.failure_rate = if rate.is_nan() {
+            1.0
+        } else {
+            rate.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Number of latest calls the failure rate is computed over (at least 1)
+    pub fn window(mut self, calls: u32) -> Self {
+        self.window = calls.max(1);
+        self
+    }
+
+    /// Calls the window must hold before the circuit can open
+    ///
+    /// Capped at the window size.
+    pub fn min_calls(mut self, calls: u32) -> Self {
+        self.min_calls = calls;
+        self
+    }
+
+    /// Probe calls let through while half-open (at least 1)
+    ///
+    /// All of them must succeed for the circuit to close.
+    pub fn probes(mut self, probes: u32) -> Self {
+        self.probes = probes.max(1);
+        self
+    }
+
+    /// Use a custom [`Clock`] to time the cool-down
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + Send + Sync + 'static,
+    {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Current state of the circuit
+    pub fn state(&self) -> CircuitState {
+        let now = self.clock.now();
+        let mut state = self.lock();
+        state.refresh(now);
+        match state.phase {
+            Phase::Closed => CircuitState::Closed,
+            Phase::Open { .. } => CircuitState::Open,
+            Phase::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Ask to make a call
+    ///
+    /// Returns `None` while the circuit is open, or half-open with all
+    /// probes taken. Report the outcome of a granted call through the
+    /// returned [`CircuitPermit`].
+    pub fn try_acquire(&self) -> Option<CircuitPermit> {
+        let now = self.clock.now();
+        let mut state = self.lock();
+        state.refresh(now);
+        match state.phase {
+            Phase::Closed => {}
+            Phase::Open { .. } => return None,
+            Phase::HalfOpen {
+                ref mut admitted, ..
+            } => {
+                if *admitted >= self.probes {
+                    return None;
+                }
+                *admitted += 1;
+            }
+        }
+        Some(CircuitPermit {
+            breaker: Some(self.clone()),
+        })
+    }
+
+    /// Report a successful call
+    pub fn record_success(&self) {
+        let mut state = self.lock();
+        match state.phase {
+            Phase::Closed => self.push(&mut state, false),
+            Phase::HalfOpen {
+                ref mut succeeded, ..
+            } => {
+                *succeeded += 1;
+                if *succeeded >= self.probes {
+                    state.phase = Phase::Closed;
+                    state.trips = 0;
+                    state.cool_down = None;
+                }
+            }
+            // Late result of a call made before the circuit opened
+            Phase::Open { .. } => {}
+        }
+    }
+
+    /// Report a failed call
+    pub fn record_failure(&self) {
+        let now = self.clock.now();
+        let mut state = self.lock();
+        match state.phase {
+            Phase::Closed => {
+                self.push(&mut state, true);
+                let calls = state.window.len() as u32;
+                let rate = state.failures as f64 / calls as f64;
+                if calls >= self.min_calls.min(self.window) && rate >= self.failure_rate {
+                    self.trip(&mut state, now);
+                }
+            }
+            Phase::HalfOpen { .. } => self.trip(&mut state, now),
+            Phase::Open { .. } => {}
+        }
+    }
+
+    /// Add an outcome to the window, dropping the oldest one if full
+    fn push(&self, state: &mut State, failed: bool) {
+        if state.window.len() as u32 >= self.window
+            && let Some(true) = state.window.pop_front()
+        {
+            state.failures -= 1;
+        }
+        state.window.push_back(failed);
+        if failed {
+            state.failures += 1;
+        }
+    }
+
+    /// Open the circuit for the next cool-down
+    fn trip(&self, state: &mut State, now: Duration) {
+        state.trips = state.trips.saturating_add(1);
+        let cool_down = (self.cool_down)(state.trips, state.cool_down)
+            .or(state.cool_down)
+            .unwrap_or_default();
+        state.cool_down = Some(cool_down);
+        state.phase = Phase::Open {
+            until: now.saturating_add(cool_down),
+        };
+        state.clear_window();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Permission to make one call, granted by [`CircuitBreaker::try_acquire`]
+///
+/// Report how the call went with [`success`](Self::success) or
+/// [`failure`](Self::failure). A permit dropped without either, because the
+/// call panicked or its future was dropped mid-flight, counts as a failure,
+/// so it can't hold on to a half-open probe slot forever.
+#[must_use = "dropping the permit reports a failed call"]
+pub struct CircuitPermit {
+    breaker: Option<CircuitBreaker>,
+}
+
+impl CircuitPermit {
+    /// Report a successful call
+    pub fn success(mut self) {
+        if let Some(breaker) = self.breaker.take() {
+            breaker.record_success();
+        }
+    }
+
+    /// Report a failed call
+    pub fn failure(mut self) {
+        if let Some(breaker) = self.breaker.take() {
+            breaker.record_failure();
+        }
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        if let Some(breaker) = self.breaker.take() {
+            breaker.record_failure();
+        }
+    }
+}
+
+impl fmt::Debug for CircuitPermit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CircuitPermit").finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for CircuitBreaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CircuitBreaker")
+            .field("state", &self.state())
+            .field("failure_rate", &self.failure_rate)
+            .field("window", &self.window)
+            .field("min_calls", &self.min_calls)
+            .field("probes", &self.probes)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backoff::{ConstantBackoff, LinearBackoff};
+    use crate::clock::ManualClock;
+
+    fn breaker(clock: &ManualClock) -> CircuitBreaker {
+        CircuitBreaker::new(ConstantBackoff::new().delay_ms(1_000).jitter_factor(0.0))
+            .window(4)
+            .min_calls(2)
+            .failure_rate(0.5)
+            .clock(clock.clone())
+    }
+
+    #[test]
+    fn test_opens_at_failure_rate() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock);
+
+        // One failure is below the minimum number of calls
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.record_success();
+        breaker.record_success();
+        breaker.record_failure();
+        // 2 of 4
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.try_acquire().is_none());
+    }
+
+    #[test]
+    fn test_window_forgets_old_calls() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock).min_calls(4).failure_rate(0.75);
+
+        breaker.record_failure();
+        breaker.record_failure();
+        for _ in 0..4 {
+            breaker.record_success();
+        }
+        // The early failures slid out of the window
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_half_open_limits_probes_and_closes() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock).probes(2);
+        breaker.record_failure();
+        breaker.record_failure();
+
+        clock.advance(Duration::from_millis(999));
+        assert!(breaker.try_acquire().is_none());
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        let first = breaker.try_acquire().expect("first probe");
+        let second = breaker.try_acquire().expect("second probe");
+        assert!(breaker.try_acquire().is_none());
+
+        first.success();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        second.success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.try_acquire().is_some());
+    }
+
+    #[test]
+    fn test_dropped_probe_counts_as_failure() {
+        let clock = ManualClock::new();
+        let breaker = breaker(&clock);
+        breaker.record_failure();
+        breaker.record_failure();
+        clock.advance(Duration::from_millis(1_000));
+
+        // The probe's call never reports back, e.g. it panicked
+        drop(breaker.try_acquire().expect("probe"));
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // The next cool-down admits a fresh probe
+        clock.advance(Duration::from_millis(1_000));
+        breaker.try_acquire().expect("probe").success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_failed_probe_reopens_with_next_cool_down() {
+        let clock = ManualClock::new();
+        let breaker = CircuitBreaker::new(
+            LinearBackoff::new()
+                .base_delay_ms(100)
+                .step_ms(100)
+                .max_attempts(3)
+                .jitter_factor(0.0),
+        )
+        .min_calls(1)
+        .clock(clock.clone());
+
+        let reopen = || {
+            breaker.try_acquire().expect("probe").failure();
+            assert_eq!(breaker.state(), CircuitState::Open);
+        };
+
+        reopen();
+        clock.advance(Duration::from_millis(100));
+        reopen();
+        clock.advance(Duration::from_millis(100));
+        assert!(breaker.try_acquire().is_none());
+
+        // The strategy is out of delays: keep the last cool-down
+        clock.advance(Duration::from_millis(100));
+        reopen();
+        clock.advance(Duration::from_millis(200));
+        breaker.try_acquire().expect("probe").success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
@@ -0,0 +1,69 @@
+//! Error classification for retry decisions
+//!
+//! [`RetryBuilder::when`](crate::retry::RetryBuilder::when) can only answer
+//! yes or no. A [`Classify`] implementation routes each error to a richer
+//! [`Classification`]: retry on the configured schedule, retry after a given
+//! delay, retry on a different backoff policy, or fail immediately.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::classify::{Classification, Classify};
+//! use chrono_machines::{BackoffPolicy, ConstantBackoff};
+//! use core::time::Duration;
+//!
+//! enum DbError {
+//!     Deadlock,
+//!     PoolExhausted,
+//!     Throttled { retry_after: Duration },
+//!     ConstraintViolation,
+//! }
+//!
+//! struct DbClassifier;
+//!
+//! impl Classify<DbError> for DbClassifier {
+//!     fn classify(&self, error: &DbError) -> Classification {
+//!         match error {
+//!             DbError::Deadlock => Classification::Retry,
+//!             DbError::PoolExhausted => Classification::RetryWithPolicy(BackoffPolicy::from(
+//!                 ConstantBackoff::new().delay_ms(50),
+//!             )),
+//!             DbError::Throttled { retry_after } => Classification::RetryAfter(*retry_after),
+//!             DbError::ConstraintViolation => Classification::Fail,
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::backoff::BackoffPolicy;
+use core::time::Duration;
+
+/// How the retry loop should treat a failed attempt
+#[derive(Debug, Clone)]
+pub enum Classification {
+    /// Retry using the builder's backoff strategy
+    Retry,
+    /// Retry after exactly this delay
+    RetryAfter(Duration),
+    /// Retry using the delay this policy computes for the current attempt
+    RetryWithPolicy(BackoffPolicy),
+    /// Do not retry; fail with the error
+    Fail,
+}
+
+/// Classifies errors into a [`Classification`]
+///
+/// Implemented for closures `Fn(&E) -> Classification`.
+pub trait Classify<E> {
+    /// Decide how to treat `error`
+    fn classify(&self, error: &E) -> Classification;
+}
+
+impl<E, F> Classify<E> for F
+where
+    F: Fn(&E) -> Classification,
+{
+    fn classify(&self, error: &E) -> Classification {
+        self(error)
+    }
+}
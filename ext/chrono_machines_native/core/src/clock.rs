@@ -0,0 +1,166 @@
+//! Clock abstraction for measuring elapsed retry time
+//!
+//! The retry loop reads time through the [`Clock`] trait when an elapsed-time
+//! budget is configured, so deadlines work with the standard library clock,
+//! embedded timers, or a simulated clock in tests.
+
+use core::time::Duration;
+
+/// Source of monotonic time
+///
+/// Implementations report the time elapsed since an arbitrary but fixed
+/// origin. Only differences between two readings are meaningful.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::clock::Clock;
+/// use core::cell::Cell;
+/// use core::time::Duration;
+///
+/// // A clock driven by an embedded tick counter
+/// struct TickClock<'a>(&'a Cell<u64>);
+///
+/// impl Clock for TickClock<'_> {
+///     fn now(&self) -> Duration {
+///         Duration::from_millis(self.0.get())
+///     }
+/// }
+/// ```
+pub trait Clock {
+    /// Current time, measured from the clock's origin
+    fn now(&self) -> Duration;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// Standard library clock backed by [`std::time::Instant`]
+///
+/// Only available when the `std` feature is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::clock::{Clock, StdClock};
+///
+/// let clock = StdClock::new();
+/// let start = clock.now();
+/// assert!(clock.now() >= start);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct StdClock {
+    origin: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl StdClock {
+    /// Create a clock whose origin is the current instant
+    pub fn new() -> Self {
+        Self {
+            origin: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StdClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// Manually advanced clock for tests (requires `std` feature)
+///
+/// Time only moves when [`advance`](Self::advance) is called, typically by a
+/// [`VirtualSleeper`](crate::sleep::VirtualSleeper) sharing the clock. Clones
+/// share the same time, so one handle can drive the retry loop while the test
+/// keeps another to inspect it.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::clock::{Clock, ManualClock};
+/// use core::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// clock.advance(Duration::from_secs(5));
+/// assert_eq!(clock.now(), Duration::from_secs(5));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: std::sync::Arc<std::sync::Mutex<Duration>>,
+}
+
+#[cfg(feature = "std")]
+impl ManualClock {
+    /// Create a clock reading zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.lock();
+        *now = now.saturating_add(duration);
+    }
+
+    /// Set the clock to an absolute reading
+    pub fn set(&self, now: Duration) {
+        *self.lock() = now;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Duration> {
+        self.now
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.lock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_clock_advances() {
+        let clock = StdClock::new();
+        let start = clock.now();
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert!(clock.now() - start >= Duration::from_millis(5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_manual_clock_shared_between_clones() {
+        let clock = ManualClock::new();
+        let handle = clock.clone();
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        handle.advance(Duration::from_millis(250));
+        assert_eq!(clock.now(), Duration::from_millis(250));
+
+        clock.set(Duration::from_secs(1));
+        assert_eq!(handle.now(), Duration::from_secs(1));
+    }
+}
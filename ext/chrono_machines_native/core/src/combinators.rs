@@ -0,0 +1,678 @@
+//! Combinators for composing backoff strategies
+//!
+//! Every [`BackoffStrategy`] gains adapter methods through
+//! [`BackoffStrategyExt`], so floors, caps, scaling and limits can be layered
+//! on top of an existing strategy instead of copying it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::{BackoffStrategyExt, ConstantBackoff, ExponentialBackoff};
+//!
+//! // Three quick retries, then exponential backoff floored at 250ms
+//! let backoff = ConstantBackoff::new()
+//!     .delay_ms(50)
+//!     .max_attempts(4)
+//!     .chain(3, ExponentialBackoff::new().base_delay_ms(100))
+//!     .with_min_delay(250)
+//!     .with_max_attempts(8);
+//! ```
+
+use crate::backoff::{duration_as_ms, duration_as_ms_f64, duration_from_ms_f64, BackoffStrategy};
+use core::time::Duration;
+use rand::Rng;
+
+/// Adapter methods available on every [`BackoffStrategy`].
+///
+/// Adapters apply to the delay produced by the wrapped strategy, after
+/// jitter, and preserve its attempt limit unless stated otherwise.
+pub trait BackoffStrategyExt: BackoffStrategy + Sized {
+    /// Never wait less than `min_delay_ms` between attempts.
+    fn with_min_delay(self, min_delay_ms: u64) -> MinDelay<Self> {
+        self.with_min_delay_duration(Duration::from_millis(min_delay_ms))
+    }
+
+    /// Never wait less than `min_delay` between attempts.
+    fn with_min_delay_duration(self, min_delay: Duration) -> MinDelay<Self> {
+        MinDelay {
+            inner: self,
+            min_delay,
+        }
+    }
+
+    /// Never wait more than `max_delay_ms` between attempts.
+    fn with_max_delay(self, max_delay_ms: u64) -> MaxDelay<Self> {
+        self.with_max_delay_duration(Duration::from_millis(max_delay_ms))
+    }
+
+    /// Never wait more than `max_delay` between attempts.
+    fn with_max_delay_duration(self, max_delay: Duration) -> MaxDelay<Self> {
+        MaxDelay {
+            inner: self,
+            max_delay,
+        }
+    }
+
+    /// Multiply every delay by `factor`.
+    ///
+    /// Negative or `NaN` factors produce zero delays.
+    fn scaled(self, factor: f64) -> Scaled<Self> {
+        Scaled {
+            inner: self,
+            factor,
+        }
+    }
+
+    /// Round every delay up to the next multiple of `tick_ms`.
+    ///
+    /// Useful when the underlying timer has a coarse resolution. A tick of
+    /// `0` leaves delays unchanged.
+    fn quantized(self, tick_ms: u64) -> Quantized<Self> {
+        self.quantized_duration(Duration::from_millis(tick_ms))
+    }
+
+    /// Round every delay up to the next multiple of `tick`.
+    ///
+    /// A zero tick leaves delays unchanged.
+    fn quantized_duration(self, tick: Duration) -> Quantized<Self> {
+        Quantized { inner: self, tick }
+    }
+
+    /// Use this strategy for the first `after` retries, then switch to `other`.
+    ///
+    /// `other` sees attempt numbers restarting at 1 once it takes over. If
+    /// this strategy runs out of attempts before `after` retries, `other`
+    /// takes over early.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{BackoffStrategy, BackoffStrategyExt, Jitter};
+    /// use chrono_machines::{ConstantBackoff, ExponentialBackoff};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let backoff = ConstantBackoff::new()
+    ///     .delay_ms(10)
+    ///     .max_attempts(10)
+    ///     .jitter(Jitter::None)
+    ///     .chain(
+    ///         2,
+    ///         ExponentialBackoff::new().base_delay_ms(100).jitter(Jitter::None),
+    ///     );
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// assert_eq!(backoff.delay(1, &mut rng), Some(10));
+    /// assert_eq!(backoff.delay(2, &mut rng), Some(10));
+    /// assert_eq!(backoff.delay(3, &mut rng), Some(100));
+    /// assert_eq!(backoff.delay(4, &mut rng), Some(200));
+    /// assert_eq!(backoff.max_attempts(), Some(5));
+    /// ```
+    fn chain<B: BackoffStrategy>(self, after: u32, other: B) -> Chain<Self, B> {
+        Chain {
+            first: self,
+            second: other,
+            after,
+        }
+    }
+
+    /// Override the attempt limit of this strategy.
+    ///
+    /// Lowering the limit stops retries early. Raising it past the wrapped
+    /// strategy's own limit keeps retrying at the delay of its final attempt.
+    fn with_max_attempts(self, max_attempts: u32) -> MaxAttempts<Self> {
+        MaxAttempts {
+            inner: self,
+            max_attempts,
+        }
+    }
+}
+
+impl<S: BackoffStrategy> BackoffStrategyExt for S {}
+
+/// Strategy adapter enforcing a minimum delay.
+///
+/// Created by [`BackoffStrategyExt::with_min_delay`].
+#[derive(Debug, Clone, Copy)]
+pub struct MinDelay<B> {
+    /// Wrapped strategy
+    pub inner: B,
+    /// Smallest delay
+    pub min_delay: Duration,
+}
+
+impl<B: BackoffStrategy> BackoffStrategy for MinDelay<B> {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        self.next_delay(attempt, None, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        self.next_delay_duration(attempt, previous_delay_ms.map(Duration::from_millis), rng)
+            .map(duration_as_ms)
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        self.inner
+            .next_delay_duration(attempt, previous_delay, rng)
+            .map(|delay| delay.max(self.min_delay))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        self.inner.should_retry(attempt)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.inner.max_attempts()
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.inner.max_elapsed()
+    }
+}
+
+/// Strategy adapter enforcing a maximum delay.
+///
+/// Created by [`BackoffStrategyExt::with_max_delay`].
+#[derive(Debug, Clone, Copy)]
+pub struct MaxDelay<B> {
+    /// Wrapped strategy
+    pub inner: B,
+    /// Largest delay
+    pub max_delay: Duration,
+}
+
+impl<B: BackoffStrategy> BackoffStrategy for MaxDelay<B> {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        self.next_delay(attempt, None, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        self.next_delay_duration(attempt, previous_delay_ms.map(Duration::from_millis), rng)
+            .map(duration_as_ms)
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        self.inner
+            .next_delay_duration(attempt, previous_delay, rng)
+            .map(|delay| delay.min(self.max_delay))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        self.inner.should_retry(attempt)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.inner.max_attempts()
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.inner.max_elapsed()
+    }
+}
+
+/// Strategy adapter multiplying every delay by a constant factor.
+///
+/// Created by [`BackoffStrategyExt::scaled`].
+#[derive(Debug, Clone, Copy)]
+pub struct Scaled<B> {
+    /// Wrapped strategy
+    pub inner: B,
+    /// Multiplier applied to each delay
+    pub factor: f64,
+}
+
+impl<B: BackoffStrategy> BackoffStrategy for Scaled<B> {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        self.next_delay(attempt, None, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        self.next_delay_duration(attempt, previous_delay_ms.map(Duration::from_millis), rng)
+            .map(duration_as_ms)
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        self.inner
+            .next_delay_duration(attempt, previous_delay, rng)
+            .map(|delay| duration_from_ms_f64(duration_as_ms_f64(delay) * self.factor))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        self.inner.should_retry(attempt)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.inner.max_attempts()
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.inner.max_elapsed()
+    }
+}
+
+/// Strategy adapter rounding delays up to a fixed tick.
+///
+/// Created by [`BackoffStrategyExt::quantized`].
+#[derive(Debug, Clone, Copy)]
+pub struct Quantized<B> {
+    /// Wrapped strategy
+    pub inner: B,
+    /// Tick size (zero disables rounding)
+    pub tick: Duration,
+}
+
+impl<B> Quantized<B> {
+    /// Round `delay` up to the next multiple of the tick.
+    fn round_up(&self, delay: Duration) -> Duration {
+        if self.tick.is_zero() {
+            return delay;
+        }
+
+        let tick = self.tick.as_nanos();
+        let nanos = match delay.as_nanos() % tick {
+            0 => delay.as_nanos(),
+            remainder => delay.as_nanos() + (tick - remainder),
+        };
+        duration_from_nanos(nanos)
+    }
+}
+
+impl<B: BackoffStrategy> BackoffStrategy for Quantized<B> {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        self.next_delay(attempt, None, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        self.next_delay_duration(attempt, previous_delay_ms.map(Duration::from_millis), rng)
+            .map(duration_as_ms)
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        self.inner
+            .next_delay_duration(attempt, previous_delay, rng)
+            .map(|delay| self.round_up(delay))
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        self.inner.should_retry(attempt)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.inner.max_attempts()
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.inner.max_elapsed()
+    }
+}
+
+/// Strategy adapter handing over from one strategy to another.
+///
+/// Created by [`BackoffStrategyExt::chain`].
+#[derive(Debug, Clone, Copy)]
+pub struct Chain<A, B> {
+    /// Strategy used for the first retries
+    pub first: A,
+    /// Strategy used once `first` is done
+    pub second: B,
+    /// Number of retries served by `first`
+    pub after: u32,
+}
+
+impl<A: BackoffStrategy, B: BackoffStrategy> Chain<A, B> {
+    /// Number of retries actually served by the first strategy.
+    fn switch_at(&self) -> u32 {
+        match self.first.max_attempts() {
+            Some(max) => self.after.min(max.saturating_sub(1)),
+            None => self.after,
+        }
+    }
+}
+
+impl<A: BackoffStrategy, B: BackoffStrategy> BackoffStrategy for Chain<A, B> {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        self.next_delay(attempt, None, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        self.next_delay_duration(attempt, previous_delay_ms.map(Duration::from_millis), rng)
+            .map(duration_as_ms)
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        let switch_at = self.switch_at();
+        if attempt <= switch_at {
+            self.first.next_delay_duration(attempt, previous_delay, rng)
+        } else {
+            self.second
+                .next_delay_duration(attempt - switch_at, previous_delay, rng)
+        }
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        let switch_at = self.switch_at();
+        if attempt <= switch_at {
+            self.first.should_retry(attempt)
+        } else {
+            self.second.should_retry(attempt - switch_at)
+        }
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.second
+            .max_attempts()
+            .map(|max| self.switch_at().saturating_add(max))
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        match (self.first.max_elapsed(), self.second.max_elapsed()) {
+            (Some(first), Some(second)) => Some(first.min(second)),
+            (first, second) => first.or(second),
+        }
+    }
+}
+
+/// Strategy adapter overriding the attempt limit.
+///
+/// Created by [`BackoffStrategyExt::with_max_attempts`].
+#[derive(Debug, Clone, Copy)]
+pub struct MaxAttempts<B> {
+    /// Wrapped strategy
+    pub inner: B,
+    /// Maximum number of attempts, including the first
+    pub max_attempts: u32,
+}
+
+impl<B: BackoffStrategy> BackoffStrategy for MaxAttempts<B> {
+    fn delay<R: Rng>(&self, attempt: u32, rng: &mut R) -> Option<u64> {
+        self.next_delay(attempt, None, rng)
+    }
+
+    fn next_delay<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay_ms: Option<u64>,
+        rng: &mut R,
+    ) -> Option<u64> {
+        self.next_delay_duration(attempt, previous_delay_ms.map(Duration::from_millis), rng)
+            .map(duration_as_ms)
+    }
+
+    fn next_delay_duration<R: Rng>(
+        &self,
+        attempt: u32,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        // Past the inner limit, repeat the delay of its last retry
+        let attempt = match self.inner.max_attempts() {
+            Some(0 | 1) => return None,
+            Some(inner_max) => attempt.min(inner_max - 1),
+            None => attempt,
+        };
+        self.inner.next_delay_duration(attempt, previous_delay, rng)
+    }
+
+    fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        Some(self.max_attempts)
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.inner.max_elapsed()
+    }
+}
+
+/// Build a [`Duration`] from nanoseconds, saturating at [`Duration::MAX`].
+fn duration_from_nanos(nanos: u128) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    match u64::try_from(nanos / NANOS_PER_SEC) {
+        Ok(secs) => Duration::new(secs, (nanos % NANOS_PER_SEC) as u32),
+        Err(_) => Duration::MAX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backoff::{ConstantBackoff, ExponentialBackoff, FibonacciBackoff};
+    use crate::jitter::Jitter;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn exponential() -> ExponentialBackoff {
+        ExponentialBackoff::new()
+            .base_delay_ms(100)
+            .multiplier(2.0)
+            .max_delay_ms(10_000)
+            .max_attempts(5)
+            .jitter(Jitter::None)
+    }
+
+    #[test]
+    fn test_min_and_max_delay() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let backoff = exponential().with_min_delay(150).with_max_delay(300);
+
+        assert_eq!(backoff.delay(1, &mut rng), Some(150));
+        assert_eq!(backoff.delay(2, &mut rng), Some(200));
+        assert_eq!(backoff.delay(3, &mut rng), Some(300));
+        assert_eq!(backoff.delay(5, &mut rng), None);
+        assert_eq!(backoff.max_attempts(), Some(5));
+    }
+
+    #[test]
+    fn test_duration_bounds_and_ticks() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let backoff = ConstantBackoff::new()
+            .interval(Duration::from_micros(120))
+            .jitter(Jitter::None);
+
+        let floored = backoff.with_min_delay_duration(Duration::from_micros(250));
+        assert_eq!(
+            floored.delay_duration(1, &mut rng),
+            Some(Duration::from_micros(250))
+        );
+
+        let capped = backoff.with_max_delay_duration(Duration::from_micros(80));
+        assert_eq!(
+            capped.delay_duration(1, &mut rng),
+            Some(Duration::from_micros(80))
+        );
+
+        let ticked = backoff.quantized_duration(Duration::from_micros(50));
+        assert_eq!(
+            ticked.delay_duration(1, &mut rng),
+            Some(Duration::from_micros(150))
+        );
+    }
+
+    #[test]
+    fn test_scaled() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let backoff = exponential().scaled(0.5);
+        assert_eq!(backoff.delay(1, &mut rng), Some(50));
+        assert_eq!(backoff.delay(3, &mut rng), Some(200));
+
+        assert_eq!(exponential().scaled(-1.0).delay(1, &mut rng), Some(0));
+        assert_eq!(exponential().scaled(f64::NAN).delay(1, &mut rng), Some(0));
+    }
+
+    #[test]
+    fn test_quantized() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let backoff = FibonacciBackoff::new()
+            .base_delay_ms(30)
+            .max_attempts(6)
+            .jitter(Jitter::None)
+            .quantized(50);
+
+        // Fibonacci delays 30, 30, 60, 90, 150 rounded up to 50ms ticks
+        let delays: Vec<_> = (1..6).filter_map(|a| backoff.delay(a, &mut rng)).collect();
+        assert_eq!(delays, vec![50, 50, 100, 100, 150]);
+
+        assert_eq!(exponential().quantized(0).delay(1, &mut rng), Some(100));
+    }
+
+    #[test]
+    fn test_chain() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let backoff = ConstantBackoff::new()
+            .delay_ms(10)
+            .max_attempts(10)
+            .jitter(Jitter::None)
+            .chain(3, exponential());
+
+        let delays: Vec<_> = (1..10).map(|a| backoff.delay(a, &mut rng)).collect();
+        assert_eq!(
+            delays,
+            vec![
+                Some(10),
+                Some(10),
+                Some(10),
+                Some(100),
+                Some(200),
+                Some(400),
+                Some(800),
+                None,
+                None
+            ]
+        );
+        assert_eq!(backoff.max_attempts(), Some(8));
+        assert!(backoff.should_retry(7));
+        assert!(!backoff.should_retry(8));
+    }
+
+    #[test]
+    fn test_chain_hands_over_when_first_exhausted() {
+        let mut rng = StdRng::seed_from_u64(42);
+        // Default constant backoff allows 3 attempts, i.e. only 2 retries
+        let backoff = ConstantBackoff::new()
+            .delay_ms(10)
+            .jitter(Jitter::None)
+            .chain(5, exponential());
+
+        assert_eq!(backoff.delay(2, &mut rng), Some(10));
+        assert_eq!(backoff.delay(3, &mut rng), Some(100));
+        assert_eq!(backoff.max_attempts(), Some(7));
+    }
+
+    #[test]
+    fn test_with_max_attempts() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let lowered = exponential().with_max_attempts(2);
+        assert_eq!(lowered.delay(1, &mut rng), Some(100));
+        assert_eq!(lowered.delay(2, &mut rng), None);
+        assert!(!lowered.should_retry(2));
+
+        // Raising the limit repeats the last inner delay
+        let raised = exponential().with_max_attempts(8);
+        assert_eq!(raised.delay(4, &mut rng), Some(800));
+        assert_eq!(raised.delay(7, &mut rng), Some(800));
+        assert_eq!(raised.delay(8, &mut rng), None);
+        assert_eq!(raised.max_attempts(), Some(8));
+    }
+
+    #[test]
+    fn test_combinators_drive_retry_loop() {
+        use crate::retry::Retryable;
+        use crate::sleep::FnSleeper;
+        use core::cell::RefCell;
+
+        let delays = RefCell::new(Vec::new());
+
+        let err = (|| Err::<(), _>("boom"))
+            .retry(
+                ConstantBackoff::new()
+                    .delay_ms(5)
+                    .jitter(Jitter::None)
+                    .chain(1, exponential())
+                    .with_max_attempts(4),
+            )
+            .notify(|ctx| {
+                delays.borrow_mut().push(ctx.next_delay_ms.unwrap());
+            })
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
+
+        assert_eq!(err.attempts(), 4);
+        assert_eq!(err.max_attempts(), Some(4));
+        assert_eq!(*delays.borrow(), vec![5, 100, 200]);
+    }
+
+    #[test]
+    fn test_chain_into_unlimited_strategy() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let backoff = ConstantBackoff::new()
+            .delay_ms(10)
+            .max_attempts(10)
+            .jitter(Jitter::None)
+            .chain(3, exponential().max_delay_ms(1_000).unlimited());
+
+        assert_eq!(backoff.max_attempts(), None);
+        assert!(backoff.should_retry(10_000));
+        assert_eq!(backoff.delay(10_000, &mut rng), Some(1_000));
+
+        let capped = backoff.with_max_attempts(500);
+        assert_eq!(capped.delay(499, &mut rng), Some(1_000));
+        assert_eq!(capped.delay(500, &mut rng), None);
+    }
+}
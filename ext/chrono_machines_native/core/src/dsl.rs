@@ -9,7 +9,7 @@ use crate::retry::{RetryBuilder, RetryError, RetryOutcome, Retryable};
 use std::fmt;
 
 /// Type alias for retry builder with policy from global registry
-type PolicyRetryBuilder<F, T, E> = RetryBuilder<F, BackoffPolicy, T, E, fn(&E) -> bool>;
+type PolicyRetryBuilder<'a, F, T, E> = RetryBuilder<'a, F, BackoffPolicy, T, E, fn(&E) -> bool>;
 
 /// Errors produced by the DSL helpers.
 #[derive(Debug)]
@@ -41,10 +41,10 @@ where
 impl<E> std::error::Error for DslError<E> where E: fmt::Display + std::error::Error {}
 
 /// Construct a [`RetryBuilder`] using a named policy from the global registry.
-pub fn builder_for_policy<F, T, E>(
+pub fn builder_for_policy<'a, F, T, E>(
     policy_name: &str,
     operation: F,
-) -> Result<PolicyRetryBuilder<F, T, E>, DslError<E>>
+) -> Result<PolicyRetryBuilder<'a, F, T, E>, DslError<E>>
 where
     F: FnMut() -> Result<T, E>,
 {
@@ -58,7 +58,7 @@ where
 pub fn retry_with_policy<F, T, E>(
     policy_name: &str,
     operation: F,
-) -> Result<RetryOutcome<T>, DslError<E>>
+) -> Result<RetryOutcome<T, E>, DslError<E>>
 where
     F: FnMut() -> Result<T, E>,
 {
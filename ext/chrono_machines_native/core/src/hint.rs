@@ -0,0 +1,56 @@
+//! Retry hints supplied by the error itself
+//!
+//! Rate-limited upstreams often say exactly when to come back (an HTTP
+//! `Retry-After` header, a gRPC `RetryInfo` detail, ...). Error types expose
+//! that through [`RetryHint`], and
+//! [`RetryBuilder::respect_retry_hints`](crate::retry::RetryBuilder::respect_retry_hints)
+//! prefers the suggested delay over the backoff strategy.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::hint::{RetryAdvice, RetryHint};
+//! use core::time::Duration;
+//!
+//! enum ApiError {
+//!     RateLimited { retry_after_secs: u64 },
+//!     Unauthorized,
+//!     Timeout,
+//! }
+//!
+//! impl RetryHint for ApiError {
+//!     fn retry_hint(&self) -> Option<RetryAdvice> {
+//!         match self {
+//!             ApiError::RateLimited { retry_after_secs } => Some(RetryAdvice::RetryAfter(
+//!                 Duration::from_secs(*retry_after_secs),
+//!             )),
+//!             ApiError::Unauthorized => Some(RetryAdvice::DoNotRetry),
+//!             // No opinion: fall back to the backoff strategy
+//!             ApiError::Timeout => None,
+//!         }
+//!     }
+//! }
+//! ```
+
+use core::time::Duration;
+
+/// Advice an error gives about retrying it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryAdvice {
+    /// Retry after the given delay instead of the strategy's delay
+    RetryAfter(Duration),
+    /// Do not retry this error
+    DoNotRetry,
+}
+
+/// Errors that can suggest how (or whether) to retry them
+pub trait RetryHint {
+    /// Suggested handling for this error, or `None` to defer to the strategy
+    fn retry_hint(&self) -> Option<RetryAdvice>;
+}
+
+impl<T: RetryHint + ?Sized> RetryHint for &T {
+    fn retry_hint(&self) -> Option<RetryAdvice> {
+        (**self).retry_hint()
+    }
+}
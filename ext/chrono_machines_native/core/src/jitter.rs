@@ -0,0 +1,201 @@
+//! Jitter modes for randomizing backoff delays
+//!
+//! Every built-in backoff strategy accepts a [`Jitter`] value describing how
+//! its deterministic delay is randomized. The same modes are exposed through
+//! the Ruby FFI so both sides compute identical distributions.
+
+use rand::Rng;
+use rand::RngExt;
+
+/// Jitter algorithm applied to a computed backoff delay.
+///
+/// All modes operate on the capped delay produced by a strategy. Results are
+/// never negative.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::{ExponentialBackoff, Jitter};
+///
+/// let backoff = ExponentialBackoff::new()
+///     .base_delay_ms(100)
+///     .jitter(Jitter::Equal); // 50-100% of each delay
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Jitter {
+    /// Use the delay unchanged.
+    None,
+    /// Uniform value in `[0, delay]` ("full jitter").
+    #[default]
+    Full,
+    /// Half the delay plus a uniform value in `[0, delay / 2]` ("equal jitter").
+    Equal,
+    /// Blend between no jitter (`0.0`) and full jitter (`1.0`).
+    ///
+    /// The delay is scaled by a uniform value in `[1 - factor, 1]`. The factor
+    /// is clamped to `[0.0, 1.0]`; `NaN` is treated as full jitter.
+    Proportional(f64),
+    /// Add a uniform offset in `[min_ms, max_ms]` milliseconds to the delay.
+    ///
+    /// The offset is added after the strategy's maximum delay cap, so a
+    /// jittered delay can exceed that cap by up to `max_ms`.
+    Bounded {
+        /// Smallest offset added, in milliseconds
+        min_ms: u64,
+        /// Largest offset added, in milliseconds
+        max_ms: u64,
+    },
+    /// Add normally distributed noise with a standard deviation expressed as
+    /// a fraction of the delay (`0.1` = 10%).
+    Gaussian {
+        /// Standard deviation relative to the delay
+        stddev: f64,
+    },
+}
+
+impl Jitter {
+    /// Apply this jitter mode to a delay expressed in milliseconds.
+    ///
+    /// Fractional milliseconds are preserved so callers working in other
+    /// units (such as the Ruby binding, which uses seconds) can convert the
+    /// result without losing precision.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::Jitter;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let delay = Jitter::Equal.apply(1000.0, &mut rng);
+    /// assert!((500.0..=1000.0).contains(&delay));
+    /// ```
+    pub fn apply<R: Rng>(&self, delay_ms: f64, rng: &mut R) -> f64 {
+        let jittered = match *self {
+            Jitter::None => delay_ms,
+            Jitter::Full => delay_ms * rng.random_range(0.0..=1.0),
+            Jitter::Equal => {
+                let half = delay_ms / 2.0;
+                half + half * rng.random_range(0.0..=1.0)
+            }
+            Jitter::Proportional(factor) => {
+                let factor = if factor.is_nan() {
+                    1.0
+                } else {
+                    factor.clamp(0.0, 1.0)
+                };
+                let random_scalar: f64 = rng.random_range(0.0..=1.0);
+                delay_ms * (1.0 - factor + random_scalar * factor)
+            }
+            Jitter::Bounded { min_ms, max_ms } => {
+                let (low, high) = if min_ms <= max_ms {
+                    (min_ms, max_ms)
+                } else {
+                    (max_ms, min_ms)
+                };
+                delay_ms + rng.random_range(low..=high) as f64
+            }
+            Jitter::Gaussian { stddev } => {
+                let stddev = if stddev.is_nan() { 0.0 } else { stddev.abs() };
+                delay_ms + delay_ms * stddev * standard_normal(rng)
+            }
+        };
+
+        jittered.max(0.0)
+    }
+}
+
+/// Approximate a standard normal sample without `std` float intrinsics.
+///
+/// Uses the Irwin-Hall construction: the sum of twelve uniform samples minus
+/// six has mean 0 and variance 1, and is bounded to `[-6, 6]`.
+fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let mut sum = 0.0;
+    for _ in 0..12 {
+        sum += rng.random_range(0.0..1.0);
+    }
+    sum - 6.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_none_and_full() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(Jitter::None.apply(250.0, &mut rng), 250.0);
+        for _ in 0..100 {
+            let delay = Jitter::Full.apply(250.0, &mut rng);
+            assert!((0.0..=250.0).contains(&delay));
+        }
+    }
+
+    #[test]
+    fn test_equal_jitter_keeps_half() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let delay = Jitter::Equal.apply(1000.0, &mut rng);
+            assert!((500.0..=1000.0).contains(&delay), "got {delay}");
+        }
+    }
+
+    #[test]
+    fn test_proportional_matches_factor() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let delay = Jitter::Proportional(0.1).apply(1000.0, &mut rng);
+            assert!((900.0..=1000.0).contains(&delay), "got {delay}");
+        }
+        assert_eq!(Jitter::Proportional(-3.0).apply(1000.0, &mut rng), 1000.0);
+        assert!(Jitter::Proportional(f64::NAN).apply(1000.0, &mut rng) <= 1000.0);
+    }
+
+    #[test]
+    fn test_bounded_adds_offset() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let delay = Jitter::Bounded {
+                min_ms: 10,
+                max_ms: 50,
+            }
+            .apply(100.0, &mut rng);
+            assert!((110.0..=150.0).contains(&delay), "got {delay}");
+        }
+
+        // Swapped bounds are normalized
+        let delay = Jitter::Bounded {
+            min_ms: 50,
+            max_ms: 10,
+        }
+        .apply(100.0, &mut rng);
+        assert!((110.0..=150.0).contains(&delay), "got {delay}");
+    }
+
+    #[test]
+    fn test_gaussian_centered_and_non_negative() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let samples = 2_000;
+        let mut total = 0.0;
+
+        for _ in 0..samples {
+            let delay = Jitter::Gaussian { stddev: 0.1 }.apply(1000.0, &mut rng);
+            assert!((400.0..=1600.0).contains(&delay), "got {delay}");
+            total += delay;
+        }
+
+        let mean = total / samples as f64;
+        assert!((980.0..=1020.0).contains(&mean), "mean {mean}");
+
+        // Large deviations never produce negative delays
+        for _ in 0..100 {
+            assert!(Jitter::Gaussian { stddev: 5.0 }.apply(10.0, &mut rng) >= 0.0);
+        }
+    }
+}
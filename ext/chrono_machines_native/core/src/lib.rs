@@ -16,7 +16,7 @@
 //! use chrono_machines::Policy;
 //!
 //! let policy = Policy {
-//!     max_attempts: 5,
+//!     max_attempts: Some(5),
 //!     base_delay_ms: 100,
 //!     multiplier: 2.0,
 //!     max_delay_ms: 10_000,
@@ -35,20 +35,50 @@
 extern crate alloc;
 
 pub mod backoff;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod budget;
+#[cfg(feature = "std")]
+pub mod cancel;
+#[cfg(feature = "std")]
+pub mod circuit;
+pub mod classify;
+pub mod clock;
+pub mod combinators;
 #[cfg(feature = "std")]
 pub mod dsl;
+pub mod hint;
+pub mod jitter;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub mod policy;
 #[cfg(any(feature = "std", feature = "alloc"))]
+pub mod retrier;
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub mod retry;
 pub mod sleep;
+pub mod stop;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use backoff::ScheduleBackoff;
 pub use backoff::{
-    fibonacci, BackoffPolicy, BackoffStrategy, ConstantBackoff, ExponentialBackoff,
-    FibonacciBackoff,
+    fibonacci, BackoffPolicy, BackoffStrategy, ConstantBackoff, DecorrelatedJitterBackoff,
+    ExponentialBackoff, FibonacciBackoff, FixedScheduleBackoff, LinearBackoff, PolynomialBackoff,
+    StaticScheduleBackoff,
 };
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use budget::RetryBudget;
+#[cfg(feature = "std")]
+pub use cancel::CancellationToken;
+#[cfg(feature = "std")]
+pub use circuit::{CircuitBreaker, CircuitPermit, CircuitState};
+pub use classify::{Classification, Classify};
+pub use clock::Clock;
+#[cfg(feature = "std")]
+pub use clock::{ManualClock, StdClock};
+pub use combinators::BackoffStrategyExt;
 #[cfg(feature = "std")]
 pub use dsl::{builder_for_policy, retry_with_policy, DslError};
+pub use hint::{RetryAdvice, RetryHint};
+pub use jitter::Jitter;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use policy::PolicyRegistry;
 #[cfg(feature = "std")]
@@ -57,14 +87,32 @@ pub use policy::{
     remove_global_policy,
 };
 #[cfg(any(feature = "std", feature = "alloc"))]
-pub use retry::{RetryBuilder, RetryContext, RetryError, RetryOutcome, Retryable, RetryableExt};
-#[cfg(feature = "std")]
-pub use sleep::StdSleeper;
+pub use retrier::Retrier;
+#[cfg(feature = "async")]
+pub use retry::{AsyncOperation, AsyncRetryable, AsyncRetryableWithInfo};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use retry::{
+    AttemptInfo, AttemptRecord, DelaySource, LocalHooks, Operation, Rejection, RetryBuilder,
+    RetryContext, RetryError, RetryErrorKind, RetryOutcome, Retryable, RetryableExt,
+    RetryableWithInfo, SendHooks, WithAttemptInfo,
+};
+#[cfg(feature = "async")]
+pub use sleep::AsyncSleeper;
+#[cfg(feature = "async-std")]
+pub use sleep::AsyncStdSleeper;
+#[cfg(feature = "embassy")]
+pub use sleep::EmbassySleeper;
+#[cfg(feature = "smol")]
+pub use sleep::SmolSleeper;
+#[cfg(feature = "tokio")]
+pub use sleep::TokioSleeper;
 pub use sleep::{FnSleeper, Sleeper};
+#[cfg(feature = "std")]
+pub use sleep::{RecordingSleeper, StdSleeper, VirtualSleeper};
+pub use stop::{StopCondition, StopConditionExt};
 
 #[cfg(feature = "std")]
 use rand::rngs::StdRng;
-use rand::RngExt;
 
 use rand::Rng;
 
@@ -73,8 +121,8 @@ use rand::Rng;
 /// Defines the parameters for exponential backoff with jitter.
 #[derive(Debug, Clone, Copy)]
 pub struct Policy {
-    /// Maximum number of retry attempts
-    pub max_attempts: u8,
+    /// Maximum number of attempts, including the first (`None` retries forever)
+    pub max_attempts: Option<u32>,
 
     /// Base delay in milliseconds
     pub base_delay_ms: u64,
@@ -97,13 +145,25 @@ impl Policy {
     /// - `max_delay_ms`: 10_000
     pub fn new() -> Self {
         Self {
-            max_attempts: 3,
+            max_attempts: Some(3),
             base_delay_ms: 100,
             multiplier: 2.0,
             max_delay_ms: 10_000,
         }
     }
 
+    /// Set the maximum number of attempts
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Retry without an attempt limit
+    pub fn unlimited(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
     /// Calculate delay with jitter for the given attempt
     ///
     /// Applies exponential backoff with configurable jitter to prevent
@@ -133,7 +193,7 @@ impl Policy {
     /// assert!(delay >= 90 && delay <= 100);
     /// ```
     #[cfg(feature = "std")]
-    pub fn calculate_delay(&self, attempt: u8, jitter_factor: f64) -> u64 {
+    pub fn calculate_delay(&self, attempt: u32, jitter_factor: f64) -> u64 {
         let mut rng: StdRng = rand::make_rng();
         self.calculate_delay_with_rng(attempt, jitter_factor, &mut rng)
     }
@@ -157,35 +217,62 @@ impl Policy {
     /// Delay in milliseconds as a `u64`
     pub fn calculate_delay_with_rng<R: Rng>(
         &self,
-        attempt: u8,
+        attempt: u32,
         jitter_factor: f64,
         rng: &mut R,
     ) -> u64 {
-        // Normalize jitter factor to the inclusive range [0.0, 1.0]
-        let mut jitter_factor = jitter_factor;
-        if jitter_factor.is_nan() {
-            jitter_factor = 1.0;
-        } else {
-            jitter_factor = jitter_factor.clamp(0.0, 1.0);
-        }
+        // Proportional jitter clamps the factor and treats NaN as full jitter
+        self.calculate_delay_with_jitter_rng(attempt, Jitter::Proportional(jitter_factor), rng)
+    }
+
+    /// Calculate delay using a [`Jitter`] mode
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{Jitter, Policy};
+    ///
+    /// let policy = Policy::new();
+    /// // Equal jitter keeps at least half of the computed delay
+    /// let delay = policy.calculate_delay_with_jitter(1, Jitter::Equal);
+    /// assert!(delay >= 50 && delay <= 100);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn calculate_delay_with_jitter(&self, attempt: u32, jitter: Jitter) -> u64 {
+        let mut rng: StdRng = rand::make_rng();
+        self.calculate_delay_with_jitter_rng(attempt, jitter, &mut rng)
+    }
 
+    /// Calculate delay using a [`Jitter`] mode and a provided RNG
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Current attempt number (1-indexed)
+    /// * `jitter` - Jitter mode applied to the capped delay
+    /// * `rng` - Random number generator implementing `Rng`
+    ///
+    /// # Returns
+    ///
+    /// Delay in milliseconds as a `u64`
+    pub fn calculate_delay_with_jitter_rng<R: Rng>(
+        &self,
+        attempt: u32,
+        jitter: Jitter,
+        rng: &mut R,
+    ) -> u64 {
         // Calculate base exponential backoff
-        let exponent = attempt.saturating_sub(1) as i32;
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
         let base_exponential =
             (self.base_delay_ms as f64) * crate::backoff::powi_f64(self.multiplier, exponent);
 
-        // Cap at max_delay
-        let capped = base_exponential.min(self.max_delay_ms as f64);
-
-        // Apply jitter: blend between deterministic and random delay
-        // jitter_factor of 1.0 = full jitter (0 to base), 0.0 = no jitter (exactly base)
-        // Formula: base * (1 - jitter_factor + rand * jitter_factor)
-        // Example with jitter_factor=0.1: base * (0.9 + rand*0.1) = 90% to 100% of base
-        let random_scalar: f64 = rng.random_range(0.0..=1.0);
-        let jitter_blend = 1.0 - jitter_factor + random_scalar * jitter_factor;
-        let jittered = capped * jitter_blend;
+        // Cap at max_delay (0 * inf is NaN when the exponent overflows)
+        let capped = if base_exponential.is_nan() {
+            0.0
+        } else {
+            base_exponential.min(self.max_delay_ms as f64)
+        };
 
-        jittered as u64
+        jitter.apply(capped, rng) as u64
     }
 
     /// Check if another retry should be attempted
@@ -197,8 +284,8 @@ impl Policy {
     /// # Returns
     ///
     /// `true` if another retry is allowed, `false` otherwise
-    pub fn should_retry(&self, current_attempt: u8) -> bool {
-        current_attempt < self.max_attempts
+    pub fn should_retry(&self, current_attempt: u32) -> bool {
+        !crate::backoff::attempts_exhausted(current_attempt, self.max_attempts)
     }
 }
 
@@ -217,7 +304,7 @@ mod tests {
     #[test]
     fn test_policy_default() {
         let policy = Policy::default();
-        assert_eq!(policy.max_attempts, 3);
+        assert_eq!(policy.max_attempts, Some(3));
         assert_eq!(policy.base_delay_ms, 100);
         assert_eq!(policy.multiplier, 2.0);
         assert_eq!(policy.max_delay_ms, 10_000);
@@ -226,7 +313,7 @@ mod tests {
     #[test]
     fn test_calculate_delay_bounds() {
         let policy = Policy {
-            max_attempts: 5,
+            max_attempts: Some(5),
             base_delay_ms: 100,
             multiplier: 2.0,
             max_delay_ms: 1000,
@@ -249,21 +336,22 @@ mod tests {
 
     #[test]
     fn test_should_retry() {
-        let policy = Policy {
-            max_attempts: 3,
-            ..Policy::default()
-        };
+        let policy = Policy::new().max_attempts(3);
 
         assert!(policy.should_retry(1));
         assert!(policy.should_retry(2));
         assert!(!policy.should_retry(3));
         assert!(!policy.should_retry(4));
+
+        let unlimited = policy.unlimited();
+        assert_eq!(unlimited.max_attempts, None);
+        assert!(unlimited.should_retry(u32::MAX));
     }
 
     #[test]
     fn test_max_delay_cap() {
         let policy = Policy {
-            max_attempts: 10,
+            max_attempts: Some(10),
             base_delay_ms: 100,
             multiplier: 2.0,
             max_delay_ms: 500,
@@ -279,7 +367,7 @@ mod tests {
     #[test]
     fn test_zero_multiplier() {
         let policy = Policy {
-            max_attempts: 5,
+            max_attempts: Some(5),
             base_delay_ms: 100,
             multiplier: 1.0, // No exponential growth
             max_delay_ms: 10_000,
@@ -297,7 +385,7 @@ mod tests {
     #[test]
     fn test_jitter_factor() {
         let policy = Policy {
-            max_attempts: 5,
+            max_attempts: Some(5),
             base_delay_ms: 1000,
             multiplier: 1.0,
             max_delay_ms: 10_000,
@@ -308,7 +396,7 @@ mod tests {
         // 10% jitter: delay should be between 900ms (90%) and 1000ms (100%)
         let delay = policy.calculate_delay_with_rng(1, 0.1, &mut rng);
         assert!(
-            delay >= 900 && delay <= 1000,
+            (900..=1000).contains(&delay),
             "delay {} not in range 900-1000",
             delay
         );
@@ -322,10 +410,40 @@ mod tests {
         assert!(delay <= 1000);
     }
 
+    #[test]
+    fn test_calculate_delay_with_jitter_modes() {
+        let policy = Policy {
+            max_attempts: Some(5),
+            base_delay_ms: 1000,
+            multiplier: 2.0,
+            max_delay_ms: 10_000,
+        };
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(
+            policy.calculate_delay_with_jitter_rng(2, Jitter::None, &mut rng),
+            2000
+        );
+
+        let delay = policy.calculate_delay_with_jitter_rng(2, Jitter::Equal, &mut rng);
+        assert!((1000..=2000).contains(&delay), "got {delay}");
+
+        let delay = policy.calculate_delay_with_jitter_rng(
+            1,
+            Jitter::Bounded {
+                min_ms: 100,
+                max_ms: 200,
+            },
+            &mut rng,
+        );
+        assert!((1100..=1200).contains(&delay), "got {delay}");
+    }
+
     #[test]
     fn test_jitter_factor_clamping() {
         let policy = Policy {
-            max_attempts: 5,
+            max_attempts: Some(5),
             base_delay_ms: 1000,
             multiplier: 1.0,
             max_delay_ms: 10_000,
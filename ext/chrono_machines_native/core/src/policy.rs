@@ -158,18 +158,55 @@ mod tests {
 
         let policy = BackoffPolicy::from(ExponentialBackoff::new().max_attempts(5));
         assert!(registry.register("api", policy).is_none());
-        assert_eq!(registry.get("api").unwrap().max_attempts(), 5);
+        assert_eq!(registry.get("api").unwrap().max_attempts(), Some(5));
 
         let new_policy = BackoffPolicy::from(ExponentialBackoff::new().max_attempts(3));
         let replaced = registry.register("api", new_policy);
-        assert_eq!(replaced.unwrap().max_attempts(), 5);
-        assert_eq!(registry.get("api").unwrap().max_attempts(), 3);
+        assert_eq!(replaced.unwrap().max_attempts(), Some(5));
+        assert_eq!(registry.get("api").unwrap().max_attempts(), Some(3));
 
         let removed = registry.remove("api");
         assert!(removed.is_some());
         assert!(registry.get("api").is_none());
     }
 
+    #[test]
+    fn test_registry_stores_schedules() {
+        use crate::backoff::StaticScheduleBackoff;
+
+        static SLA_DELAYS: [u64; 3] = [50, 200, 1_000];
+
+        let mut registry = PolicyRegistry::new();
+        registry.register("sla", StaticScheduleBackoff::new(&SLA_DELAYS).into());
+
+        match registry.get("sla") {
+            Some(BackoffPolicy::Schedule(schedule)) => {
+                assert_eq!(schedule.delays_ms, [50, 200, 1_000]);
+                assert_eq!(schedule.max_attempts, Some(4));
+            }
+            other => panic!("expected schedule policy, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_registry_stores_elapsed_budget() {
+        use crate::backoff::{BackoffStrategy, ConstantBackoff};
+        use core::time::Duration;
+
+        let mut registry = PolicyRegistry::new();
+        let policy = ConstantBackoff::new()
+            .unlimited()
+            .max_elapsed(Duration::from_secs(30));
+        registry.register("poll", policy.into());
+
+        let fetched = registry.get("poll").unwrap();
+        assert_eq!(fetched.max_attempts(), None);
+        assert_eq!(
+            BackoffStrategy::max_elapsed(&fetched),
+            Some(Duration::from_secs(30))
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_global_registry_roundtrip() {
@@ -180,10 +217,10 @@ mod tests {
         assert!(register_global_policy("workers", policy).is_none());
 
         let fetched = get_global_policy("workers").unwrap();
-        assert_eq!(fetched.max_attempts(), 4);
+        assert_eq!(fetched.max_attempts(), Some(4));
 
         let removed = remove_global_policy("workers").unwrap();
-        assert_eq!(removed.max_attempts(), 4);
+        assert_eq!(removed.max_attempts(), Some(4));
         assert!(get_global_policy("workers").is_none());
     }
 }
@@ -0,0 +1,563 @@
+//! Reusable retry configuration
+//!
+//! A [`RetryBuilder`] binds one operation and is consumed by `call`. A
+//! [`Retrier`] holds everything else (backoff strategy, predicate, hooks,
+//! stop condition, time budget, [retry budget](crate::budget) and circuit
+//! breaker) and runs any number of operations. Build it once, share it
+//! behind an `Arc`, and [`run`](Retrier::run) each request through it.
+//!
+//! Every run shares the same hooks, possibly on several threads at once, so
+//! they are `Fn` rather than `FnMut` and must be `Send + Sync + 'static`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::retrier::Retrier;
+//! use chrono_machines::ExponentialBackoff;
+//! use std::sync::Arc;
+//!
+//! #[derive(Debug)]
+//! enum ApiError {
+//!     Timeout,
+//!     NotFound,
+//! }
+//!
+//! let retrier = Arc::new(
+//!     Retrier::new(ExponentialBackoff::new().base_delay_ms(1).max_attempts(3))
+//!         .when(|e: &ApiError| matches!(e, ApiError::Timeout))
+//!         .notify(|ctx| eprintln!("attempt {} failed: {:?}", ctx.attempt, ctx.error)),
+//! );
+//!
+//! # #[cfg(feature = "std")]
+//! # {
+//! let user = retrier.run(|| Ok::<_, ApiError>("alice"));
+//! assert_eq!(user.unwrap().into_inner(), "alice");
+//!
+//! let missing = retrier.run(|| Err::<(), _>(ApiError::NotFound));
+//! assert_eq!(missing.unwrap_err().attempts(), 1);
+//! # }
+//! ```
+
+use crate::backoff::BackoffStrategy;
+use crate::budget::RetryBudget;
+#[cfg(feature = "std")]
+use crate::circuit::CircuitBreaker;
+use crate::classify::{Classification, Classify};
+use crate::clock::Clock;
+use crate::hint::RetryHint;
+use crate::retry::{HintPolicy, RetryBuilder, RetryContext, RetryError, SendHooks};
+#[cfg(feature = "std")]
+use crate::retry::{Operation, RetryOutcome};
+use crate::stop::{StopCondition, StopState};
+#[cfg(all(feature = "async", feature = "std"))]
+use crate::{retry::AsyncOperation, sleep::AsyncSleeper};
+use core::convert::Infallible;
+use core::time::Duration;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::boxed::Box;
+
+/// Type alias for a boxed retry predicate
+type Predicate<E> = Box<dyn Fn(&E) -> bool + Send + Sync>;
+
+/// Type alias for a shared retry predicate
+type SharedPredicate<'r, E> = &'r (dyn Fn(&E) -> bool + Send + Sync);
+
+/// Type alias for a boxed notify callback
+type NotifyCallback<E> = Box<dyn Fn(&RetryContext<E>) + Send + Sync>;
+
+/// Type alias for a boxed failure callback
+type FailureCallback<E> = Box<dyn Fn(&RetryError<E>) + Send + Sync>;
+
+/// Type alias for the builder returned by [`Retrier::retry`]
+type RetrierBuilder<'r, F, B, T, E> =
+    RetryBuilder<'r, F, &'r B, T, E, SharedPredicate<'r, E>, Infallible, SendHooks<'r, T, E>>;
+
+/// Reusable retry configuration, independent of any operation
+///
+/// # Type Parameters
+///
+/// * `B` - The backoff strategy type
+/// * `E` - The error type of the operations it runs
+pub struct Retrier<B, E> {
+    backoff: B,
+    when: Option<Predicate<E>>,
+    classifier: Option<Box<dyn Classify<E> + Send + Sync>>,
+    notify: Option<NotifyCallback<E>>,
+    on_success: Option<NotifyCallback<E>>,
+    on_failure: Option<FailureCallback<E>>,
+    clock: Option<Box<dyn Clock + Send + Sync>>,
+    stop: Option<Box<dyn StopCondition + Send + Sync>>,
+    hints: Option<HintPolicy<E>>,
+    max_elapsed: Option<Duration>,
+    collect_errors: bool,
+    budget: Option<RetryBudget>,
+    #[cfg(feature = "std")]
+    breaker: Option<CircuitBreaker>,
+}
+
+impl<B, E> Retrier<B, E>
+where
+    B: BackoffStrategy,
+{
+    /// Create a retrier that retries every error on `backoff`'s schedule
+    pub fn new(backoff: B) -> Self {
+        Self {
+            backoff,
+            when: None,
+            classifier: None,
+            notify: None,
+            on_success: None,
+            on_failure: None,
+            clock: None,
+            stop: None,
+            hints: None,
+            max_elapsed: None,
+            collect_errors: false,
+            budget: None,
+            #[cfg(feature = "std")]
+            breaker: None,
+        }
+    }
+
+    /// Only retry errors for which `predicate` returns `true`
+    ///
+    /// See [`RetryBuilder::when`].
+    pub fn when<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        self.when = Some(Box::new(predicate));
+        self
+    }
+
+    /// Route each error through a [`Classify`] implementation
+    ///
+    /// See [`RetryBuilder::classify`].
+    pub fn classify<C>(mut self, classifier: C) -> Self
+    where
+        C: Classify<E> + Send + Sync + 'static,
+    {
+        self.classifier = Some(Box::new(classifier));
+        self
+    }
+
+    /// Callback invoked before each retry
+    ///
+    /// See [`RetryBuilder::notify`].
+    pub fn notify<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(&RetryContext<E>) + Send + Sync + 'static,
+    {
+        self.notify = Some(Box::new(callback));
+        self
+    }
+
+    /// Callback invoked after a successful attempt
+    ///
+    /// See [`RetryBuilder::on_success`].
+    pub fn on_success<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(&RetryContext<E>) + Send + Sync + 'static,
+    {
+        self.on_success = Some(Box::new(callback));
+        self
+    }
+
+    /// Callback invoked when a run terminates with failure
+    ///
+    /// See [`RetryBuilder::on_failure`].
+    pub fn on_failure<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(&RetryError<E>) + Send + Sync + 'static,
+    {
+        self.on_failure = Some(Box::new(callback));
+        self
+    }
+
+    /// Use a custom [`Clock`] to measure elapsed time against the budget
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + Send + Sync + 'static,
+    {
+        self.clock = Some(Box::new(clock));
+        self
+    }
+
+    /// Decide when to give up with a [`StopCondition`]
+    ///
+    /// See [`RetryBuilder::stop_when`].
+    pub fn stop_when<S>(mut self, condition: S) -> Self
+    where
+        S: StopCondition + Send + Sync + 'static,
+    {
+        self.stop = Some(Box::new(condition));
+        self
+    }
+
+    /// Prefer the delay suggested by the error's [`RetryHint`], capped at `cap`
+    ///
+    /// See [`RetryBuilder::respect_retry_hints`].
+    pub fn respect_retry_hints(mut self, cap: Duration) -> Self
+    where
+        E: RetryHint,
+    {
+        self.hints = Some(HintPolicy::new(cap));
+        self
+    }
+
+    /// Stop retrying once `budget` has elapsed since a run's first attempt
+    ///
+    /// See [`RetryBuilder::max_elapsed`].
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
+    }
+
+    /// Keep the error of every retried attempt
+    ///
+    /// See [`RetryBuilder::collect_errors`].
+    pub fn collect_errors(mut self) -> Self {
+        self.collect_errors = true;
+        self
+    }
+
+    /// Draw every run's retries from a shared [`RetryBudget`]
+    ///
+    /// See [`RetryBuilder::budget`].
+    pub fn budget(mut self, budget: RetryBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Consult a shared [`CircuitBreaker`] before every attempt of every run
+    /// (requires `std` feature)
+    ///
+    /// See [`RetryBuilder::circuit_breaker`].
+    #[cfg(feature = "std")]
+    pub fn circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
+    /// The backoff strategy runs are scheduled with
+    pub fn backoff(&self) -> &B {
+        &self.backoff
+    }
+
+    /// Builder for one run of `operation`, configured from this retrier
+    ///
+    /// Use it to add per-call settings such as a
+    /// [`fallback`](RetryBuilder::fallback), a deadline or a cancellation
+    /// token, or to pick a specific `call_*` method. Hooks added here must be
+    /// `Send`, like those of a builder started with
+    /// [`retry_send`](crate::Retryable::retry_send).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::retrier::Retrier;
+    /// use chrono_machines::sleep::FnSleeper;
+    /// use chrono_machines::ConstantBackoff;
+    ///
+    /// let retrier = Retrier::new(ConstantBackoff::new().delay_ms(10).max_attempts(3));
+    ///
+    /// let outcome = retrier
+    ///     .retry(|| Err::<u32, _>("cache miss"))
+    ///     .fallback(|_err| Ok(0))
+    ///     .call_with_sleeper(FnSleeper(|_| {}))
+    ///     .unwrap();
+    /// assert!(outcome.used_fallback());
+    /// ```
+    pub fn retry<F, T>(&self, operation: F) -> RetrierBuilder<'_, F, B, T, E> {
+        let when: SharedPredicate<'_, E> = match self.when {
+            Some(ref predicate) => predicate,
+            None => &retry_any::<E>,
+        };
+        let mut builder: RetrierBuilder<'_, F, B, T, E> =
+            RetryBuilder::new(operation, &self.backoff)
+                .when(when)
+                .hint_policy(self.hints);
+
+        if let Some(ref classifier) = self.classifier {
+            builder =
+                builder.classify(|error: &E| -> Classification { classifier.classify(error) });
+        }
+        if let Some(ref callback) = self.notify {
+            builder = builder.notify(callback);
+        }
+        if let Some(ref callback) = self.on_success {
+            builder = builder.on_success(callback);
+        }
+        if let Some(ref callback) = self.on_failure {
+            builder = builder.on_failure(callback);
+        }
+        if let Some(ref clock) = self.clock {
+            builder = builder.clock(&**clock);
+        }
+        if let Some(ref stop) = self.stop {
+            builder = builder.stop_when(SharedStop(&**stop));
+        }
+        if let Some(budget) = self.max_elapsed {
+            builder = builder.max_elapsed(budget);
+        }
+        if self.collect_errors {
+            builder = builder.collect_errors();
+        }
+        if let Some(ref budget) = self.budget {
+            builder = builder.budget(budget.clone());
+        }
+        #[cfg(feature = "std")]
+        if let Some(ref breaker) = self.breaker {
+            builder = builder.circuit_breaker(breaker.clone());
+        }
+        builder
+    }
+
+    /// Run `operation` with blocking sleep (requires `std` feature)
+    ///
+    /// Shorthand for `self.retry(operation).call()`.
+    #[cfg(feature = "std")]
+    pub fn run<F, T>(&self, operation: F) -> Result<RetryOutcome<T, E>, RetryError<E>>
+    where
+        F: Operation<T, E>,
+    {
+        self.retry(operation).call()
+    }
+
+    /// Run `operation` asynchronously (requires `async` and `std` features)
+    ///
+    /// Shorthand for `self.retry(operation).call_async(sleeper)`.
+    #[cfg(all(feature = "async", feature = "std"))]
+    pub async fn run_async<F, T, S>(
+        &self,
+        operation: F,
+        sleeper: S,
+    ) -> Result<RetryOutcome<T, E>, RetryError<E>>
+    where
+        F: AsyncOperation<T, E>,
+        S: AsyncSleeper,
+    {
+        self.retry(operation).call_async(sleeper).await
+    }
+}
+
+/// Predicate used when the retrier has none: every error is retried.
+fn retry_any<E>(_error: &E) -> bool {
+    true
+}
+
+/// Borrowed stop condition that keeps reporting its attempt limit.
+struct SharedStop<'r>(&'r (dyn StopCondition + Send + Sync));
+
+impl StopCondition for SharedStop<'_> {
+    fn should_stop(&self, state: &StopState) -> bool {
+        self.0.should_stop(state)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        self.0.max_attempts()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backoff::ConstantBackoff;
+    use crate::retry::RetryErrorKind;
+    use crate::sleep::FnSleeper;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestError {
+        Retryable,
+        Fatal,
+    }
+
+    fn flaky(failures: u32) -> impl FnMut() -> Result<u32, TestError> {
+        let mut attempts = 0;
+        move || {
+            attempts += 1;
+            if attempts <= failures {
+                Err(TestError::Retryable)
+            } else {
+                Ok(attempts)
+            }
+        }
+    }
+
+    #[test]
+    fn test_runs_many_operations_with_shared_hooks() {
+        let retries = Arc::new(AtomicU32::new(0));
+        let successes = Arc::new(AtomicU32::new(0));
+        let retry_counter = Arc::clone(&retries);
+        let success_counter = Arc::clone(&successes);
+
+        let retrier = Retrier::new(ConstantBackoff::new().delay_ms(1).max_attempts(3))
+            .when(|e: &TestError| *e == TestError::Retryable)
+            .notify(move |_| {
+                retry_counter.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_success(move |_| {
+                success_counter.fetch_add(1, Ordering::SeqCst);
+            });
+
+        assert_eq!(retrier.run(flaky(0)).unwrap().into_inner(), 1);
+        assert_eq!(retrier.run(flaky(2)).unwrap().into_inner(), 3);
+
+        let err = retrier.run(|| Err::<(), _>(TestError::Fatal)).unwrap_err();
+        assert_eq!(err.attempts(), 1);
+        assert!(matches!(err.kind(), RetryErrorKind::PredicateRejected(_)));
+
+        assert_eq!(retries.load(Ordering::SeqCst), 2);
+        assert_eq!(successes.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        let failures = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&failures);
+        let retrier = Arc::new(
+            Retrier::new(ConstantBackoff::new().delay_ms(1).max_attempts(2)).on_failure(
+                move |_: &RetryError<TestError>| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                },
+            ),
+        );
+
+        let handles: Vec<_> = (0..4)
+            .map(|worker| {
+                let retrier = Arc::clone(&retrier);
+                std::thread::spawn(move || {
+                    retrier
+                        .retry(flaky(worker))
+                        .call_with_sleeper(FnSleeper(|_| {}))
+                        .is_ok()
+                })
+            })
+            .collect();
+        let succeeded: Vec<bool> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        // Two attempts cover at most one failure
+        assert_eq!(succeeded, vec![true, true, false, false]);
+        assert_eq!(failures.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_stop_condition_and_budget_apply_per_run() {
+        let retrier = Retrier::new(ConstantBackoff::new().delay_ms(10).unlimited())
+            .stop_when(crate::stop::after_attempts(4))
+            .collect_errors();
+
+        for _ in 0..2 {
+            let err = retrier
+                .retry(|| Err::<(), _>(TestError::Retryable))
+                .call_with_sleeper(FnSleeper(|_| {}))
+                .unwrap_err();
+            assert_eq!(err.attempts(), 4);
+            assert_eq!(err.max_attempts(), Some(4));
+            assert_eq!(err.history().len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_budget_shared_between_runs() {
+        let budget = RetryBudget::new(3, 0.5);
+        let retrier = Retrier::new(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .budget(budget.clone());
+
+        let run = |failures| {
+            retrier
+                .retry(flaky(failures))
+                .call_with_sleeper(FnSleeper(|_| {}))
+        };
+
+        assert!(run(2).is_ok());
+        assert_eq!(budget.available(), 1.5);
+
+        let err = run(2).unwrap_err();
+        assert_eq!(err.kind(), RetryErrorKind::BudgetExhausted);
+        assert_eq!(err.attempts(), 2);
+        assert_eq!(budget.available(), 0.5);
+    }
+
+    #[test]
+    fn test_circuit_breaker_shared_between_runs() {
+        let breaker = CircuitBreaker::new(ConstantBackoff::new().delay_ms(60_000)).min_calls(2);
+        let retrier = Retrier::new(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .circuit_breaker(breaker.clone());
+        let calls = AtomicU32::new(0);
+
+        let err = retrier
+            .retry(|| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(TestError::Retryable)
+            })
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .unwrap_err();
+        assert_eq!(err.kind(), RetryErrorKind::CircuitOpen);
+        assert_eq!(err.attempts(), 2);
+
+        let err = retrier.run(flaky(0)).unwrap_err();
+        assert_eq!(err.kind(), RetryErrorKind::CircuitOpen);
+        assert_eq!(err.attempts(), 0);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_classifier_and_per_call_fallback() {
+        let retrier = Retrier::new(ConstantBackoff::new().delay_ms(10).max_attempts(5)).classify(
+            |e: &TestError| match e {
+                TestError::Retryable => Classification::Retry,
+                TestError::Fatal => Classification::Fail,
+            },
+        );
+
+        let outcome = retrier
+            .retry(|| Err::<u32, _>(TestError::Fatal))
+            .fallback(|_| Ok(99))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .unwrap();
+        assert!(outcome.used_fallback());
+        assert_eq!(outcome.attempts(), 1);
+        assert_eq!(outcome.into_inner(), 99);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_run_async_from_spawned_tasks() {
+        use crate::sleep::RecordingSleeper;
+
+        let retrier = Arc::new(Retrier::new(
+            ConstantBackoff::new().delay_ms(10).max_attempts(3),
+        ));
+        let sleeper = RecordingSleeper::new();
+
+        let task = {
+            let retrier = Arc::clone(&retrier);
+            let sleeper = sleeper.clone();
+            tokio::spawn(async move {
+                // The operation owns its state: futures of closures borrowing
+                // locals of the task can't be proven `Send` by the compiler
+                let mut attempts = 0;
+                let operation = move || {
+                    attempts += 1;
+                    let attempt = attempts;
+                    async move {
+                        if attempt < 3 {
+                            Err(TestError::Retryable)
+                        } else {
+                            Ok(attempt)
+                        }
+                    }
+                };
+                retrier.run_async(operation, sleeper).await
+            })
+        };
+
+        let outcome = task.await.unwrap().unwrap();
+        assert_eq!(outcome.into_inner(), 3);
+        assert_eq!(sleeper.delays().len(), 2);
+    }
+}
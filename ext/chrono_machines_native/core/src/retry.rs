@@ -3,38 +3,361 @@
 //! This module provides a fluent retry API for wrapping fallible operations
 //! with automatic retries and configurable backoff strategies.
 
-use crate::backoff::BackoffStrategy;
+use crate::backoff::{duration_as_ms, BackoffPolicy, BackoffStrategy};
+use crate::budget::RetryBudget;
+#[cfg(feature = "std")]
+use crate::cancel::CancellationToken;
+#[cfg(feature = "std")]
+use crate::circuit::{CircuitBreaker, CircuitPermit};
+use crate::classify::{Classification, Classify};
+use crate::clock::Clock;
+use crate::hint::{RetryAdvice, RetryHint};
+#[cfg(feature = "async")]
+use crate::sleep::AsyncSleeper;
 use crate::sleep::Sleeper;
+use crate::stop::{StopCondition, StopState};
+use core::convert::Infallible;
 use core::fmt;
+#[cfg(feature = "async")]
+use core::future::Future;
+use core::time::Duration;
 #[cfg(feature = "std")]
 use rand::rngs::StdRng;
 
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 /// Type alias for retry builder with default predicate
-type DefaultRetryBuilder<F, B, T, E> = RetryBuilder<F, B, T, E, fn(&E) -> bool>;
+type DefaultRetryBuilder<'a, F, B, T, E> = RetryBuilder<'a, F, B, T, E, fn(&E) -> bool>;
 
-/// Type alias for boxed notify callback
-type NotifyCallback<E> = Box<dyn FnMut(&RetryContext<E>)>;
+/// Type alias for retry builder with default predicate and `Send` hooks
+type DefaultSendRetryBuilder<'a, F, B, T, E> =
+    RetryBuilder<'a, F, B, T, E, fn(&E) -> bool, Infallible, SendHooks<'a, T, E>>;
 
-/// Type alias for boxed failure callback
-type FailureCallback<E> = Box<dyn FnMut(&RetryError<E>)>;
+/// Boxed hook types of [`LocalHooks`]
+type NotifyHook<'a, E> = Box<dyn FnMut(&RetryContext<E>) + 'a>;
+type FailureHook<'a, E> = Box<dyn FnMut(&RetryError<E>) + 'a>;
+type FallbackHook<'a, T, E> = Box<dyn FnMut(&RetryError<E>) -> Result<T, E> + 'a>;
+type OkPredicate<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
 
-/// Build a terminal [`RetryError`], firing the `on_failure` callback if present.
-fn finalize_failure<E>(
-    on_failure: Option<&mut FailureCallback<E>>,
-    kind: RetryErrorKind,
-    attempt: u8,
-    max_attempts: u8,
-    cumulative_delay_ms: u64,
-    error: E,
-) -> RetryError<E> {
-    let retry_error = RetryError::new(kind, attempt, max_attempts, cumulative_delay_ms, Some(error));
-    if let Some(callback) = on_failure {
-        callback(&retry_error);
-    }
-    retry_error
+/// Boxed hook types of [`SendHooks`]
+type SendNotifyHook<'a, E> = Box<dyn FnMut(&RetryContext<E>) + Send + 'a>;
+type SendFailureHook<'a, E> = Box<dyn FnMut(&RetryError<E>) + Send + 'a>;
+type SendFallbackHook<'a, T, E> = Box<dyn FnMut(&RetryError<E>) -> Result<T, E> + Send + 'a>;
+type SendOkPredicate<'a, T> = Box<dyn Fn(&T) -> bool + Send + 'a>;
+
+/// Callbacks and other boxed parts of a [`RetryBuilder`]
+///
+/// The default storage: hooks only need to outlive the builder, so they can
+/// capture `Rc`, `Cell` or plain references. See [`SendHooks`] for builders
+/// handed to other threads.
+pub struct LocalHooks<'a, T, E> {
+    notify: Option<NotifyHook<'a, E>>,
+    on_success: Option<NotifyHook<'a, E>>,
+    on_failure: Option<FailureHook<'a, E>>,
+    fallback: Option<FallbackHook<'a, T, E>>,
+    clock: Option<Box<dyn Clock + 'a>>,
+    stop: Option<Box<dyn StopCondition + 'a>>,
+    classifier: Option<Box<dyn Classify<E> + 'a>>,
+    retry_if_ok: Option<OkPredicate<'a, T>>,
+}
+
+/// Hook storage for builders handed to other threads: hooks must be `Send`
+///
+/// Selected with [`Retryable::retry_send`]. The builder is then `Send`
+/// whenever the operation, backoff strategy, `when` predicate and result
+/// types are. Hooks capturing thread-local state are rejected at compile
+/// time:
+///
+/// ```compile_fail
+/// use chrono_machines::{ConstantBackoff, Retryable};
+/// use std::rc::Rc;
+///
+/// let seen = Rc::new(core::cell::Cell::new(0));
+/// let builder = (|| Ok::<_, ()>(()))
+///     .retry_send(ConstantBackoff::new())
+///     .notify(move |ctx| seen.set(ctx.attempt));
+/// ```
+pub struct SendHooks<'a, T, E> {
+    notify: Option<SendNotifyHook<'a, E>>,
+    on_success: Option<SendNotifyHook<'a, E>>,
+    on_failure: Option<SendFailureHook<'a, E>>,
+    fallback: Option<SendFallbackHook<'a, T, E>>,
+    clock: Option<Box<dyn Clock + Send + 'a>>,
+    stop: Option<Box<dyn StopCondition + Send + 'a>>,
+    classifier: Option<Box<dyn Classify<E> + Send + 'a>>,
+    retry_if_ok: Option<SendOkPredicate<'a, T>>,
+}
+
+impl<T, E> Default for LocalHooks<'_, T, E> {
+    fn default() -> Self {
+        Self {
+            notify: None,
+            on_success: None,
+            on_failure: None,
+            fallback: None,
+            clock: None,
+            stop: None,
+            classifier: None,
+            retry_if_ok: None,
+        }
+    }
+}
+
+impl<T, E> Default for SendHooks<'_, T, E> {
+    fn default() -> Self {
+        Self {
+            notify: None,
+            on_success: None,
+            on_failure: None,
+            fallback: None,
+            clock: None,
+            stop: None,
+            classifier: None,
+            retry_if_ok: None,
+        }
+    }
+}
+
+// Public so it can bound public methods, but unnameable outside the crate,
+// so the two hook storages stay its only implementations.
+mod hooks {
+    use super::{Classify, Clock, RetryContext, RetryError, StopCondition};
+
+    #[cfg(all(not(feature = "std"), feature = "alloc"))]
+    use alloc::boxed::Box;
+
+    pub(super) type Notify<'h, E> = &'h mut dyn FnMut(&RetryContext<E>);
+    pub(super) type Failure<'h, E> = &'h mut dyn FnMut(&RetryError<E>);
+    pub(super) type Fallback<'h, T, E> = &'h mut dyn FnMut(&RetryError<E>) -> Result<T, E>;
+
+    /// What the retry loop needs from [`LocalHooks`](super::LocalHooks) and
+    /// [`SendHooks`](super::SendHooks)
+    pub trait RunHooks<T, E> {
+        /// Clock type, kept `Send` by `SendHooks`
+        type Clock: Clock + ?Sized;
+
+        fn notify(&mut self) -> Option<Notify<'_, E>>;
+        fn on_success(&mut self) -> Option<Notify<'_, E>>;
+        fn on_failure(&mut self) -> Option<Failure<'_, E>>;
+        fn fallback(&mut self) -> Option<Fallback<'_, T, E>>;
+        fn take_clock(&mut self) -> Option<Box<Self::Clock>>;
+        fn stop(&self) -> Option<&dyn StopCondition>;
+        fn classifier(&self) -> Option<&dyn Classify<E>>;
+        fn retry_if_ok(&self) -> Option<&dyn Fn(&T) -> bool>;
+    }
+}
+
+use hooks::RunHooks;
+
+impl<'a, T, E> RunHooks<T, E> for LocalHooks<'a, T, E> {
+    type Clock = dyn Clock + 'a;
+
+    fn notify(&mut self) -> Option<hooks::Notify<'_, E>> {
+        Some(self.notify.as_mut()?)
+    }
+
+    fn on_success(&mut self) -> Option<hooks::Notify<'_, E>> {
+        Some(self.on_success.as_mut()?)
+    }
+
+    fn on_failure(&mut self) -> Option<hooks::Failure<'_, E>> {
+        Some(self.on_failure.as_mut()?)
+    }
+
+    fn fallback(&mut self) -> Option<hooks::Fallback<'_, T, E>> {
+        Some(self.fallback.as_mut()?)
+    }
+
+    fn take_clock(&mut self) -> Option<Box<Self::Clock>> {
+        self.clock.take()
+    }
+
+    fn stop(&self) -> Option<&dyn StopCondition> {
+        self.stop.as_deref()
+    }
+
+    fn classifier(&self) -> Option<&dyn Classify<E>> {
+        self.classifier.as_deref()
+    }
+
+    fn retry_if_ok(&self) -> Option<&dyn Fn(&T) -> bool> {
+        Some(self.retry_if_ok.as_deref()?)
+    }
+}
+
+impl<'a, T, E> RunHooks<T, E> for SendHooks<'a, T, E> {
+    type Clock = dyn Clock + Send + 'a;
+
+    fn notify(&mut self) -> Option<hooks::Notify<'_, E>> {
+        Some(self.notify.as_mut()?)
+    }
+
+    fn on_success(&mut self) -> Option<hooks::Notify<'_, E>> {
+        Some(self.on_success.as_mut()?)
+    }
+
+    fn on_failure(&mut self) -> Option<hooks::Failure<'_, E>> {
+        Some(self.on_failure.as_mut()?)
+    }
+
+    fn fallback(&mut self) -> Option<hooks::Fallback<'_, T, E>> {
+        Some(self.fallback.as_mut()?)
+    }
+
+    fn take_clock(&mut self) -> Option<Box<Self::Clock>> {
+        self.clock.take()
+    }
+
+    fn stop(&self) -> Option<&dyn StopCondition> {
+        Some(self.stop.as_deref()?)
+    }
+
+    fn classifier(&self) -> Option<&dyn Classify<E>> {
+        Some(self.classifier.as_deref()?)
+    }
+
+    fn retry_if_ok(&self) -> Option<&dyn Fn(&T) -> bool> {
+        Some(self.retry_if_ok.as_deref()?)
+    }
+}
+
+/// Retry hint lookup and the cap applied to hinted delays.
+pub(crate) struct HintPolicy<E> {
+    advice: fn(&E) -> Option<RetryAdvice>,
+    cap: Duration,
+}
+
+impl<E: RetryHint> HintPolicy<E> {
+    pub(crate) fn new(cap: Duration) -> Self {
+        Self {
+            advice: E::retry_hint,
+            cap,
+        }
+    }
+}
+
+impl<E> Clone for HintPolicy<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for HintPolicy<E> {}
+
+/// Circuit breaker permit held across an attempt, if a breaker is attached.
+#[cfg(feature = "std")]
+type AttemptPermit = Option<CircuitPermit>;
+#[cfg(not(feature = "std"))]
+type AttemptPermit = ();
+
+/// Report the outcome of an attempt to the circuit breaker, if any.
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+fn report_to_circuit(permit: AttemptPermit, failed: bool) {
+    #[cfg(feature = "std")]
+    if let Some(permit) = permit {
+        if failed {
+            permit.failure();
+        } else {
+            permit.success();
+        }
+    }
+}
+
+/// Progress of a single retry run.
+struct RunState<E, V, C: ?Sized> {
+    attempt: u32,
+    max_attempts: Option<u32>,
+    cumulative_delay: Duration,
+    previous_delay: Option<Duration>,
+    last_value: Option<V>,
+    budget: Option<Duration>,
+    clock: Option<Box<C>>,
+    /// Clock used when none is configured
+    #[cfg(feature = "std")]
+    std_clock: crate::clock::StdClock,
+    start: Duration,
+    /// Errors of retried attempts, when collecting them
+    history: Option<Vec<AttemptRecord<E>>>,
+    /// When the most recent attempt failed, for its history record
+    failed_at: Duration,
+}
+
+impl<E, V, C: Clock + ?Sized> RunState<E, V, C> {
+    /// Current reading of the configured clock, or [`StdClock`](crate::clock::StdClock) with `std`.
+    fn now(&self) -> Option<Duration> {
+        if let Some(ref clock) = self.clock {
+            return Some(clock.now());
+        }
+
+        #[cfg(feature = "std")]
+        return Some(self.std_clock.now());
+
+        #[cfg(not(feature = "std"))]
+        None
+    }
+
+    /// Time elapsed since the run started.
+    ///
+    /// Without a clock, elapsed time is approximated by the time spent sleeping.
+    fn elapsed(&self) -> Duration {
+        match self.now() {
+            Some(now) => now.saturating_sub(self.start),
+            None => self.cumulative_delay,
+        }
+    }
+
+    /// Information handed to the operation for the upcoming attempt.
+    ///
+    /// When collecting errors, the previous error lives in the history.
+    fn attempt_info<'b>(&'b self, last_error: Option<&'b E>) -> AttemptInfo<'b, E> {
+        let elapsed = self.elapsed();
+        let last_error = last_error.or_else(|| {
+            self.history
+                .as_ref()
+                .and_then(|history| history.last())
+                .filter(|record| record.attempt.saturating_add(1) == self.attempt)
+                .map(|record| &record.error)
+        });
+        AttemptInfo {
+            attempt: self.attempt,
+            elapsed,
+            remaining: self.budget.map(|budget| budget.saturating_sub(elapsed)),
+            last_error,
+        }
+    }
+
+    /// Keep the error of the attempt just retried, if collecting errors.
+    ///
+    /// Returns the error back when it isn't kept.
+    fn record(&mut self, error: Option<E>) -> Option<E> {
+        match (self.history.as_mut(), error) {
+            (Some(history), Some(error)) => {
+                history.push(AttemptRecord {
+                    attempt: self.attempt.saturating_sub(1),
+                    error,
+                    delay: self.previous_delay.unwrap_or_default(),
+                    timestamp: self.failed_at,
+                });
+                None
+            }
+            (_, error) => error,
+        }
+    }
+
+    /// Hand the collected history to the final result.
+    fn take_history(&mut self) -> Option<Vec<AttemptRecord<E>>> {
+        self.history.take()
+    }
+}
+
+/// What the retry loop does after an attempt.
+enum Step<T, E, V> {
+    /// Sleep for this long, then make the next attempt. Carries the error in
+    /// case the sleep is cancelled.
+    Retry(Duration, Option<E>),
+    /// Stop with the final result.
+    Finish(Result<RetryOutcome<T, E>, RetryError<E, V>>),
 }
 
 /// Reason why a retry operation failed.
@@ -42,8 +365,40 @@ fn finalize_failure<E>(
 pub enum RetryErrorKind {
     /// The operation exhausted all retry attempts.
     Exhausted,
-    /// The error was rejected by the `when` predicate.
-    PredicateRejected,
+    /// The error was rejected before exhausting retries; the payload says by what.
+    PredicateRejected(Rejection),
+    /// The elapsed-time budget ran out before the operation succeeded.
+    DeadlineExceeded,
+    /// The retry loop was cancelled through its [`CancellationToken`](crate::cancel::CancellationToken).
+    Cancelled,
+    /// The shared [`RetryBudget`] had no token left for another retry.
+    BudgetExhausted,
+    /// The [`CircuitBreaker`](crate::circuit::CircuitBreaker) rejected the
+    /// next attempt, which was not made.
+    CircuitOpen,
+}
+
+/// What rejected an error and stopped the retry loop early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// The `when` predicate returned `false`.
+    Predicate,
+    /// The classifier returned [`Classification::Fail`].
+    Classifier,
+    /// The error's [`RetryHint`] advised [`RetryAdvice::DoNotRetry`].
+    Hint,
+}
+
+/// Where the delay before the next attempt came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelaySource {
+    /// Computed by the backoff strategy.
+    Strategy,
+    /// Suggested by the error through [`RetryHint`], capped by the builder.
+    Hint,
+    /// Chosen by the classifier via [`Classification::RetryAfter`] or
+    /// [`Classification::RetryWithPolicy`].
+    Classifier,
 }
 
 /// Context provided to retry callbacks with observability data.
@@ -53,65 +408,165 @@ pub enum RetryErrorKind {
 #[derive(Debug)]
 pub struct RetryContext<'a, E> {
     /// Current attempt number (1-indexed)
-    pub attempt: u8,
+    pub attempt: u32,
     /// Delay in milliseconds before the next retry attempt (None on success or final failure)
     pub next_delay_ms: Option<u64>,
+    /// Delay before the next retry attempt, with sub-millisecond precision
+    pub next_delay: Option<Duration>,
+    /// Origin of `next_delay` (None on success or final failure)
+    pub delay_source: Option<DelaySource>,
     /// Total milliseconds spent sleeping between attempts so far
     pub cumulative_delay_ms: u64,
+    /// Total time spent sleeping between attempts so far, with sub-millisecond precision
+    pub cumulative_delay: Duration,
     /// Reference to the error that triggered this retry (None on success)
     pub error: Option<&'a E>,
 }
 
+/// Information passed to operations that want to know where the retry loop is.
+///
+/// Received by operations built with
+/// [`retry_with_info`](RetryableWithInfo::retry_with_info), e.g. to derive a
+/// per-attempt timeout from `remaining` or to tag requests with `attempt`.
+#[derive(Debug)]
+pub struct AttemptInfo<'a, E> {
+    /// Attempt about to run (1-indexed)
+    pub attempt: u32,
+    /// Time elapsed since the first attempt started
+    ///
+    /// Without a clock (`no_std` without [`RetryBuilder::clock`]), this is
+    /// the time spent sleeping between attempts.
+    pub elapsed: Duration,
+    /// Time left in the elapsed-time budget (None when no budget is set)
+    pub remaining: Option<Duration>,
+    /// Error returned by the previous attempt (None on the first attempt, or
+    /// when the previous attempt returned a rejected `Ok` value)
+    pub last_error: Option<&'a E>,
+}
+
+/// Error of an attempt that was retried, kept by
+/// [`collect_errors`](RetryBuilder::collect_errors).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttemptRecord<E> {
+    /// Attempt that failed (1-indexed)
+    pub attempt: u32,
+    /// Error the attempt returned
+    pub error: E,
+    /// Delay slept before the next attempt
+    pub delay: Duration,
+    /// Time since the first attempt started when this attempt failed
+    pub timestamp: Duration,
+}
+
 /// Rich retry error that carries execution context.
+///
+/// `V` is the operation's success type when the builder used
+/// [`retry_if_ok`](RetryBuilder::retry_if_ok); the error then also holds the
+/// last value that was rejected. Otherwise it is [`Infallible`] and no value
+/// is ever stored.
 #[derive(Debug, Clone)]
-pub struct RetryError<E> {
+pub struct RetryError<E, V = Infallible> {
     kind: RetryErrorKind,
-    attempts: u8,
-    max_attempts: u8,
-    cumulative_delay_ms: u64,
+    attempts: u32,
+    max_attempts: Option<u32>,
+    cumulative_delay: Duration,
     cause: Option<E>,
+    last_value: Option<V>,
+    history: Option<Vec<AttemptRecord<E>>>,
 }
 
 impl<E> RetryError<E> {
     fn new(
         kind: RetryErrorKind,
-        attempts: u8,
-        max_attempts: u8,
-        cumulative_delay_ms: u64,
+        attempts: u32,
+        max_attempts: Option<u32>,
+        cumulative_delay: Duration,
         cause: Option<E>,
     ) -> Self {
         Self {
             kind,
             attempts,
             max_attempts,
-            cumulative_delay_ms,
+            cumulative_delay,
             cause,
+            last_value: None,
+            history: None,
         }
     }
 
+    fn with_history(mut self, history: Option<Vec<AttemptRecord<E>>>) -> Self {
+        self.history = history;
+        self
+    }
+
+    fn with_last_value<V>(self, last_value: Option<V>) -> RetryError<E, V> {
+        RetryError {
+            kind: self.kind,
+            attempts: self.attempts,
+            max_attempts: self.max_attempts,
+            cumulative_delay: self.cumulative_delay,
+            cause: self.cause,
+            last_value,
+            history: self.history,
+        }
+    }
+}
+
+impl<E, V> RetryError<E, V> {
     /// Retrieve the underlying cause when available.
     pub fn cause(&self) -> Option<&E> {
         self.cause.as_ref()
     }
 
     /// Consume the error and return the underlying cause when available.
+    ///
+    /// `None` when the final attempt returned an `Ok` value rejected by
+    /// [`retry_if_ok`](RetryBuilder::retry_if_ok).
     pub fn into_cause(self) -> Option<E> {
         self.cause
     }
 
+    /// Errors of the attempts before the final one, oldest first.
+    ///
+    /// Empty unless the builder used [`collect_errors`](RetryBuilder::collect_errors).
+    /// The final attempt's error is [`cause`](Self::cause).
+    pub fn history(&self) -> &[AttemptRecord<E>] {
+        self.history.as_deref().unwrap_or_default()
+    }
+
+    /// Consume the error and return the collected history.
+    pub fn into_history(self) -> Vec<AttemptRecord<E>> {
+        self.history.unwrap_or_default()
+    }
+
+    /// Most recent `Ok` value rejected by [`retry_if_ok`](RetryBuilder::retry_if_ok).
+    pub fn last_value(&self) -> Option<&V> {
+        self.last_value.as_ref()
+    }
+
+    /// Consume the error and return the most recent rejected `Ok` value.
+    pub fn into_last_value(self) -> Option<V> {
+        self.last_value
+    }
+
     /// Attempt number that produced the terminal outcome (1-indexed).
-    pub fn attempts(&self) -> u8 {
+    pub fn attempts(&self) -> u32 {
         self.attempts
     }
 
-    /// Maximum attempts allowed by the policy.
-    pub fn max_attempts(&self) -> u8 {
+    /// Maximum attempts allowed by the policy (`None` when unlimited).
+    pub fn max_attempts(&self) -> Option<u32> {
         self.max_attempts
     }
 
-    /// Total time spent in delays before reaching terminal state.
+    /// Total time spent in delays before reaching terminal state, in milliseconds.
     pub fn cumulative_delay_ms(&self) -> u64 {
-        self.cumulative_delay_ms
+        duration_as_ms(self.cumulative_delay)
+    }
+
+    /// Total time spent in delays before reaching terminal state.
+    pub fn cumulative_delay(&self) -> Duration {
+        self.cumulative_delay
     }
 
     /// Error category.
@@ -120,28 +575,52 @@ impl<E> RetryError<E> {
     }
 }
 
-impl<E> fmt::Display for RetryError<E>
+impl<E, V> fmt::Display for RetryError<E, V>
 where
     E: fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.kind {
-            RetryErrorKind::Exhausted => {
-                write!(
+            RetryErrorKind::Exhausted => match self.max_attempts {
+                Some(max_attempts) => write!(
                     f,
                     "retry exhausted after {} of {} attempts",
-                    self.attempts, self.max_attempts
+                    self.attempts, max_attempts
+                )?,
+                None => write!(f, "retry exhausted after {} attempts", self.attempts)?,
+            },
+            RetryErrorKind::PredicateRejected(rejection) => {
+                let by = match rejection {
+                    Rejection::Predicate => "predicate",
+                    Rejection::Classifier => "classifier",
+                    Rejection::Hint => "retry hint",
+                };
+                write!(f, "retry aborted by {} on attempt {}", by, self.attempts)?;
+            }
+            RetryErrorKind::DeadlineExceeded => {
+                write!(
+                    f,
+                    "retry deadline exceeded after {} attempts",
+                    self.attempts
                 )?;
             }
-            RetryErrorKind::PredicateRejected => {
-                write!(f, "retry aborted by predicate on attempt {}", self.attempts)?;
+            RetryErrorKind::Cancelled => {
+                write!(f, "retry cancelled after {} attempts", self.attempts)?;
+            }
+            RetryErrorKind::BudgetExhausted => {
+                write!(f, "retry budget exhausted after {} attempts", self.attempts)?;
+            }
+            RetryErrorKind::CircuitOpen => {
+                write!(f, "circuit open after {} attempts", self.attempts)?;
             }
         }
 
-        write!(f, " (cumulative delay {}ms)", self.cumulative_delay_ms)?;
+        write!(f, " (cumulative delay {:?})", self.cumulative_delay)?;
 
         if let Some(cause) = self.cause.as_ref() {
             write!(f, ": {}", cause)?;
+        } else if self.last_value.is_some() {
+            write!(f, ": result rejected by retry_if_ok")?;
         }
 
         Ok(())
@@ -149,33 +628,71 @@ where
 }
 
 #[cfg(feature = "std")]
-impl<E> std::error::Error for RetryError<E> where E: std::error::Error {}
+impl<E, V> std::error::Error for RetryError<E, V>
+where
+    E: std::error::Error,
+    V: fmt::Debug,
+{
+}
 
 /// Successful retry result holding metadata about the execution.
 #[derive(Debug)]
-pub struct RetryOutcome<T> {
+pub struct RetryOutcome<T, E = ()> {
     value: T,
-    attempts: u8,
-    cumulative_delay_ms: u64,
+    attempts: u32,
+    cumulative_delay: Duration,
+    fallback: bool,
+    history: Option<Vec<AttemptRecord<E>>>,
 }
 
-impl<T> RetryOutcome<T> {
-    fn new(value: T, attempts: u8, cumulative_delay_ms: u64) -> Self {
+impl<T, E> RetryOutcome<T, E> {
+    fn new(
+        value: T,
+        attempts: u32,
+        cumulative_delay: Duration,
+        history: Option<Vec<AttemptRecord<E>>>,
+    ) -> Self {
         Self {
             value,
             attempts,
-            cumulative_delay_ms,
+            cumulative_delay,
+            fallback: false,
+            history,
         }
     }
 
-    /// Attempt that succeeded (1-indexed).
-    pub fn attempts(&self) -> u8 {
+    fn with_fallback(mut self) -> Self {
+        self.fallback = true;
+        self
+    }
+
+    /// Attempt that produced the value (1-indexed); with a fallback, the
+    /// last attempt made.
+    pub fn attempts(&self) -> u32 {
         self.attempts
     }
 
+    /// Whether the value came from [`RetryBuilder::fallback`] rather than
+    /// the operation.
+    pub fn used_fallback(&self) -> bool {
+        self.fallback
+    }
+
     /// Total milliseconds spent sleeping between attempts.
     pub fn cumulative_delay_ms(&self) -> u64 {
-        self.cumulative_delay_ms
+        duration_as_ms(self.cumulative_delay)
+    }
+
+    /// Total time spent sleeping between attempts.
+    pub fn cumulative_delay(&self) -> Duration {
+        self.cumulative_delay
+    }
+
+    /// Errors of the attempts that failed before this outcome, oldest first.
+    ///
+    /// `None` unless the builder used [`collect_errors`](RetryBuilder::collect_errors).
+    pub fn history(&self) -> Option<&[AttemptRecord<E>]> {
+        self.history.as_deref()
     }
 
     /// Borrow the successful value.
@@ -220,87 +737,343 @@ pub trait Retryable<T, E> {
     /// # Returns
     ///
     /// A `RetryBuilder` that can be further configured before execution
-    fn retry<B: BackoffStrategy>(self, backoff: B) -> DefaultRetryBuilder<Self, B, T, E>
+    fn retry<'a, B: BackoffStrategy>(self, backoff: B) -> DefaultRetryBuilder<'a, Self, B, T, E>
     where
         Self: Sized;
+
+    /// Begin building a retry operation that can be handed to another thread
+    ///
+    /// Like [`retry`](Self::retry), but hooks, clocks, stop conditions,
+    /// classifiers and `retry_if_ok` predicates must be `Send`. In
+    /// return the builder is `Send` whenever the operation, backoff
+    /// strategy, `when` predicate and result types are, so it can be
+    /// configured in one place and run on a worker thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{ConstantBackoff, Retryable};
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// let retries = Arc::new(AtomicU32::new(0));
+    /// let counter = Arc::clone(&retries);
+    ///
+    /// let builder = (|| Err::<(), _>("unavailable"))
+    ///     .retry_send(ConstantBackoff::new().delay_ms(1).max_attempts(3))
+    ///     .notify(move |_ctx| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    ///
+    /// let result = std::thread::spawn(move || builder.call()).join().unwrap();
+    /// assert!(result.is_err());
+    /// assert_eq!(retries.load(Ordering::Relaxed), 2);
+    /// ```
+    fn retry_send<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultSendRetryBuilder<'a, Self, B, T, E>
+    where
+        Self: Sized + Send;
 }
 
 impl<F, T, E> Retryable<T, E> for F
 where
     F: FnMut() -> Result<T, E>,
 {
-    fn retry<B: BackoffStrategy>(self, backoff: B) -> RetryBuilder<Self, B, T, E, fn(&E) -> bool> {
-        RetryBuilder {
-            operation: self,
-            backoff,
-            when: None,
-            notify: None,
-            on_success: None,
-            on_failure: None,
-            _phantom_t: core::marker::PhantomData,
-            _phantom_e: core::marker::PhantomData,
-        }
+    fn retry<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> RetryBuilder<'a, Self, B, T, E, fn(&E) -> bool> {
+        RetryBuilder::new(self, backoff)
+    }
+
+    fn retry_send<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultSendRetryBuilder<'a, Self, B, T, E>
+    where
+        Self: Send,
+    {
+        RetryBuilder::new(self, backoff)
     }
 }
 
-/// Ergonomic extension methods for retry operations
+/// Extension trait that adds `.retry()` to async closures (requires `async` feature)
 ///
-/// This trait adds convenience methods that create retry builders with common
-/// backoff strategies using their default configurations.
+/// Implemented for every `FnMut() -> impl Future<Output = Result<T, E>>`,
+/// including `async` closures. The returned [`RetryBuilder`] is configured
+/// exactly like the blocking one and run with
+/// [`call_async`](RetryBuilder::call_async).
 ///
 /// # Example
 ///
-/// ```rust
-/// use chrono_machines::RetryableExt;
+/// Using the `TokioSleeper` from the `tokio` feature:
 ///
-/// fn fetch_data() -> Result<String, std::io::Error> {
-///     // ... operation that might fail
+#[cfg_attr(feature = "tokio", doc = "```rust")]
+#[cfg_attr(not(feature = "tokio"), doc = "```ignore")]
+/// use chrono_machines::sleep::TokioSleeper;
+/// use chrono_machines::{AsyncRetryable, ExponentialBackoff};
+///
+/// async fn fetch_data() -> Result<String, std::io::Error> {
+///     // ...
 /// #   Ok("data".to_string())
 /// }
 ///
-/// # #[cfg(feature = "std")]
-/// // Before: fetch_data.retry(ExponentialBackoff::default()).call()?;
-/// // After:  fetch_data.with_exponential().call()?;
-/// let outcome = fetch_data.with_exponential().call()?;
-/// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), chrono_machines::RetryError<std::io::Error>> {
+/// let outcome = fetch_data
+///     .retry(ExponentialBackoff::default())
+///     .call_async(TokioSleeper)
+///     .await?;
+/// assert_eq!(outcome.attempts(), 1);
+/// # Ok(())
+/// # }
 /// ```
-pub trait RetryableExt<T, E>: Retryable<T, E> {
-    /// Create a retry builder with exponential backoff using default configuration
-    ///
-    /// Default configuration:
-    /// - max_attempts: 3
-    /// - base_delay_ms: 100
-    /// - multiplier: 2.0
-    /// - max_delay_ms: 10_000
-    /// - jitter_factor: 1.0 (full jitter)
-    ///
-    /// # Returns
-    ///
-    /// A `RetryBuilder` configured with `ExponentialBackoff::default()`
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use chrono_machines::RetryableExt;
-    ///
-    /// fn fetch_api() -> Result<String, std::io::Error> {
-    ///     Ok("response".to_string())
-    /// }
+#[cfg(feature = "async")]
+pub trait AsyncRetryable<T, E> {
+    /// Begin building an async retry operation with the given backoff strategy
+    fn retry<'a, B: BackoffStrategy>(self, backoff: B) -> DefaultRetryBuilder<'a, Self, B, T, E>
+    where
+        Self: Sized;
+
+    /// Begin building an async retry operation that can run in a spawned task
     ///
-    /// # #[cfg(feature = "std")]
-    /// let outcome = fetch_api.with_exponential().call()?;
-    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
-    /// ```
-    fn with_exponential(self) -> DefaultRetryBuilder<Self, crate::backoff::ExponentialBackoff, T, E>
+    /// See [`Retryable::retry_send`]. Let the operation own its state
+    /// (`move` closures): the compiler currently can't prove the future
+    /// `Send` when the operation borrows locals of the task.
+    fn retry_send<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultSendRetryBuilder<'a, Self, B, T, E>
     where
-        Self: Sized,
+        Self: Sized + Send;
+}
+
+#[cfg(feature = "async")]
+impl<F, T, E> AsyncRetryable<T, E> for F
+where
+    F: AsyncFnMut() -> Result<T, E>,
+{
+    fn retry<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> RetryBuilder<'a, Self, B, T, E, fn(&E) -> bool> {
+        RetryBuilder::new(self, backoff)
+    }
+
+    fn retry_send<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultSendRetryBuilder<'a, Self, B, T, E>
+    where
+        Self: Send,
     {
-        self.retry(crate::backoff::ExponentialBackoff::default())
+        RetryBuilder::new(self, backoff)
     }
+}
 
-    /// Create a retry builder with constant backoff
-    ///
-    /// # Arguments
+/// Extension trait that adds `.retry_with_info()` to closures taking an [`AttemptInfo`]
+///
+/// Implemented for every `FnMut(&AttemptInfo<E>) -> Result<T, E>`. The
+/// closure's parameter type has to be spelled out, since it can't be
+/// inferred from a method call.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::{AttemptInfo, ExponentialBackoff, RetryableWithInfo};
+/// use core::time::Duration;
+///
+/// fn fetch_with_timeout(timeout: Duration, attempt: u32) -> Result<String, std::io::Error> {
+///     // ... send `X-Attempt: {attempt}` and give up after `timeout`
+/// #   Ok("data".to_string())
+/// }
+///
+/// # #[cfg(feature = "std")]
+/// let outcome = (|info: &AttemptInfo<std::io::Error>| {
+///     let timeout = info.remaining.unwrap_or(Duration::from_secs(5));
+///     fetch_with_timeout(timeout.min(Duration::from_secs(5)), info.attempt)
+/// })
+/// .retry_with_info(ExponentialBackoff::default())
+/// .max_elapsed(Duration::from_secs(30))
+/// .call()?;
+/// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+/// ```
+pub trait RetryableWithInfo<T, E> {
+    /// Begin building a retry operation with the given backoff strategy
+    fn retry_with_info<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultRetryBuilder<'a, WithAttemptInfo<Self>, B, T, E>
+    where
+        Self: Sized;
+}
+
+impl<F, T, E> RetryableWithInfo<T, E> for F
+where
+    F: FnMut(&AttemptInfo<'_, E>) -> Result<T, E>,
+{
+    fn retry_with_info<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultRetryBuilder<'a, WithAttemptInfo<Self>, B, T, E> {
+        RetryBuilder::new(WithAttemptInfo(self), backoff)
+    }
+}
+
+/// Extension trait that adds `.retry_with_info()` to async closures (requires `async` feature)
+///
+/// The async counterpart of [`RetryableWithInfo`], implemented for
+/// `async` closures taking `&AttemptInfo<E>`. Run the builder with
+/// [`call_async`](RetryBuilder::call_async).
+#[cfg(feature = "async")]
+pub trait AsyncRetryableWithInfo<T, E> {
+    /// Begin building an async retry operation with the given backoff strategy
+    fn retry_with_info<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultRetryBuilder<'a, WithAttemptInfo<Self>, B, T, E>
+    where
+        Self: Sized;
+}
+
+#[cfg(feature = "async")]
+impl<F, T, E> AsyncRetryableWithInfo<T, E> for F
+where
+    F: AsyncFnMut(&AttemptInfo<'_, E>) -> Result<T, E>,
+{
+    fn retry_with_info<'a, B: BackoffStrategy>(
+        self,
+        backoff: B,
+    ) -> DefaultRetryBuilder<'a, WithAttemptInfo<Self>, B, T, E> {
+        RetryBuilder::new(WithAttemptInfo(self), backoff)
+    }
+}
+
+/// Operation run by the blocking retry loop
+///
+/// Implemented for closures `FnMut() -> Result<T, E>` and for
+/// [`WithAttemptInfo`] wrappers; there is normally no need to implement it.
+pub trait Operation<T, E> {
+    /// Run one attempt
+    fn run(&mut self, info: &AttemptInfo<'_, E>) -> Result<T, E>;
+}
+
+impl<F, T, E> Operation<T, E> for F
+where
+    F: FnMut() -> Result<T, E>,
+{
+    fn run(&mut self, _info: &AttemptInfo<'_, E>) -> Result<T, E> {
+        self()
+    }
+}
+
+impl<F, T, E> Operation<T, E> for WithAttemptInfo<F>
+where
+    F: FnMut(&AttemptInfo<'_, E>) -> Result<T, E>,
+{
+    fn run(&mut self, info: &AttemptInfo<'_, E>) -> Result<T, E> {
+        (self.0)(info)
+    }
+}
+
+/// Operation run by the async retry loop (requires `async` feature)
+///
+/// Implemented for async closures `AsyncFnMut() -> Result<T, E>` and for
+/// [`WithAttemptInfo`] wrappers; there is normally no need to implement it.
+#[cfg(feature = "async")]
+pub trait AsyncOperation<T, E> {
+    /// Run one attempt
+    fn run(&mut self, info: &AttemptInfo<'_, E>) -> impl Future<Output = Result<T, E>>;
+}
+
+#[cfg(feature = "async")]
+impl<F, T, E> AsyncOperation<T, E> for F
+where
+    F: AsyncFnMut() -> Result<T, E>,
+{
+    fn run(&mut self, _info: &AttemptInfo<'_, E>) -> impl Future<Output = Result<T, E>> {
+        self()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<F, T, E> AsyncOperation<T, E> for WithAttemptInfo<F>
+where
+    F: AsyncFnMut(&AttemptInfo<'_, E>) -> Result<T, E>,
+{
+    fn run(&mut self, info: &AttemptInfo<'_, E>) -> impl Future<Output = Result<T, E>> {
+        (self.0)(info)
+    }
+}
+
+/// Operation that receives an [`AttemptInfo`] on every attempt
+///
+/// Created by [`RetryableWithInfo::retry_with_info`].
+#[derive(Debug, Clone, Copy)]
+pub struct WithAttemptInfo<F>(F);
+
+/// Ergonomic extension methods for retry operations
+///
+/// This trait adds convenience methods that create retry builders with common
+/// backoff strategies using their default configurations.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::RetryableExt;
+///
+/// fn fetch_data() -> Result<String, std::io::Error> {
+///     // ... operation that might fail
+/// #   Ok("data".to_string())
+/// }
+///
+/// # #[cfg(feature = "std")]
+/// // Before: fetch_data.retry(ExponentialBackoff::default()).call()?;
+/// // After:  fetch_data.with_exponential().call()?;
+/// let outcome = fetch_data.with_exponential().call()?;
+/// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+/// ```
+pub trait RetryableExt<T, E>: Retryable<T, E> {
+    /// Create a retry builder with exponential backoff using default configuration
+    ///
+    /// Default configuration:
+    /// - max_attempts: 3
+    /// - base_delay_ms: 100
+    /// - multiplier: 2.0
+    /// - max_delay_ms: 10_000
+    /// - jitter_factor: 1.0 (full jitter)
+    ///
+    /// # Returns
+    ///
+    /// A `RetryBuilder` configured with `ExponentialBackoff::default()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::RetryableExt;
+    ///
+    /// fn fetch_api() -> Result<String, std::io::Error> {
+    ///     Ok("response".to_string())
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let outcome = fetch_api.with_exponential().call()?;
+    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+    /// ```
+    fn with_exponential<'a>(
+        self,
+    ) -> DefaultRetryBuilder<'a, Self, crate::backoff::ExponentialBackoff, T, E>
+    where
+        Self: Sized,
+    {
+        self.retry(crate::backoff::ExponentialBackoff::default())
+    }
+
+    /// Create a retry builder with constant backoff
+    ///
+    /// # Arguments
     ///
     /// * `delay_ms` - Fixed delay in milliseconds between retry attempts
     ///
@@ -326,7 +1099,10 @@ pub trait RetryableExt<T, E>: Retryable<T, E> {
     /// let outcome = check_status.with_constant(500).call()?;
     /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
     /// ```
-    fn with_constant(self, delay_ms: u64) -> DefaultRetryBuilder<Self, crate::backoff::ConstantBackoff, T, E>
+    fn with_constant<'a>(
+        self,
+        delay_ms: u64,
+    ) -> DefaultRetryBuilder<'a, Self, crate::backoff::ConstantBackoff, T, E>
     where
         Self: Sized,
     {
@@ -360,12 +1136,122 @@ pub trait RetryableExt<T, E>: Retryable<T, E> {
     /// let outcome = connect_database.with_fibonacci().call()?;
     /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
     /// ```
-    fn with_fibonacci(self) -> DefaultRetryBuilder<Self, crate::backoff::FibonacciBackoff, T, E>
+    fn with_fibonacci<'a>(
+        self,
+    ) -> DefaultRetryBuilder<'a, Self, crate::backoff::FibonacciBackoff, T, E>
     where
         Self: Sized,
     {
         self.retry(crate::backoff::FibonacciBackoff::default())
     }
+
+    /// Create a retry builder with linear backoff using default configuration
+    ///
+    /// Default configuration:
+    /// - max_attempts: 3
+    /// - base_delay_ms: 100
+    /// - step_ms: 100
+    /// - max_delay_ms: 10_000
+    /// - jitter: full
+    ///
+    /// Delays grow by a fixed step: 100ms, 200ms, 300ms...
+    ///
+    /// # Returns
+    ///
+    /// A `RetryBuilder` configured with `LinearBackoff::default()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::RetryableExt;
+    ///
+    /// fn sync_inventory() -> Result<(), std::io::Error> {
+    ///     Ok(())
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let outcome = sync_inventory.with_linear().call()?;
+    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+    /// ```
+    fn with_linear<'a>(self) -> DefaultRetryBuilder<'a, Self, crate::backoff::LinearBackoff, T, E>
+    where
+        Self: Sized,
+    {
+        self.retry(crate::backoff::LinearBackoff::default())
+    }
+
+    /// Create a retry builder with polynomial backoff using default configuration
+    ///
+    /// Default configuration:
+    /// - max_attempts: 3
+    /// - base_delay_ms: 100
+    /// - exponent: 2 (quadratic)
+    /// - max_delay_ms: 10_000
+    /// - jitter: full
+    ///
+    /// Delays grow quadratically: 100ms, 400ms, 900ms...
+    ///
+    /// # Returns
+    ///
+    /// A `RetryBuilder` configured with `PolynomialBackoff::default()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::RetryableExt;
+    ///
+    /// fn refresh_token() -> Result<String, std::io::Error> {
+    ///     Ok("token".to_string())
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let outcome = refresh_token.with_polynomial().call()?;
+    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+    /// ```
+    fn with_polynomial<'a>(
+        self,
+    ) -> DefaultRetryBuilder<'a, Self, crate::backoff::PolynomialBackoff, T, E>
+    where
+        Self: Sized,
+    {
+        self.retry(crate::backoff::PolynomialBackoff::default())
+    }
+
+    /// Create a retry builder with decorrelated jitter backoff using default configuration
+    ///
+    /// Default configuration:
+    /// - max_attempts: 3
+    /// - base_delay_ms: 100
+    /// - max_delay_ms: 10_000
+    ///
+    /// Each delay is drawn from `[base_delay_ms, previous_delay * 3]`, which
+    /// spreads out retries from many clients failing at the same time.
+    ///
+    /// # Returns
+    ///
+    /// A `RetryBuilder` configured with `DecorrelatedJitterBackoff::default()`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::RetryableExt;
+    ///
+    /// fn poll_queue() -> Result<u32, std::io::Error> {
+    ///     Ok(3)
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let outcome = poll_queue.with_decorrelated().call()?;
+    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+    /// ```
+    fn with_decorrelated<'a>(
+        self,
+    ) -> DefaultRetryBuilder<'a, Self, crate::backoff::DecorrelatedJitterBackoff, T, E>
+    where
+        Self: Sized,
+    {
+        self.retry(crate::backoff::DecorrelatedJitterBackoff::default())
+    }
 }
 
 // Blanket implementation for all Retryable types
@@ -383,64 +1269,103 @@ impl<F, T, E> RetryableExt<T, E> for F where F: Retryable<T, E> {}
 /// * `T` - The success return type
 /// * `E` - The error type
 /// * `W` - The when predicate type
-pub struct RetryBuilder<F, B, T, E, W> {
+/// * `V` - The value kept in [`RetryError`] (see [`retry_if_ok`](Self::retry_if_ok))
+/// * `H` - Where hooks are stored: [`LocalHooks`], or [`SendHooks`] after [`retry_send`](Retryable::retry_send)
+pub struct RetryBuilder<'a, F, B, T, E, W, V = Infallible, H = LocalHooks<'a, T, E>> {
     operation: F,
     backoff: B,
     when: Option<W>,
-    notify: Option<NotifyCallback<E>>,
-    on_success: Option<NotifyCallback<E>>,
-    on_failure: Option<FailureCallback<E>>,
-    _phantom_t: core::marker::PhantomData<T>,
-    _phantom_e: core::marker::PhantomData<E>,
+    hooks: H,
+    /// Keeps a value rejected by `retry_if_ok` for the error
+    keep_rejected: Option<fn(T) -> V>,
+    max_elapsed: Option<Duration>,
+    #[cfg(feature = "std")]
+    deadline: Option<std::time::Instant>,
+    hints: Option<HintPolicy<E>>,
+    collect_errors: bool,
+    budget: Option<RetryBudget>,
+    #[cfg(feature = "std")]
+    cancel: Option<CancellationToken>,
+    #[cfg(feature = "std")]
+    breaker: Option<CircuitBreaker>,
+    _phantom: core::marker::PhantomData<fn() -> &'a (T, E)>,
+}
+
+impl<F, B, T, E, H: Default> RetryBuilder<'_, F, B, T, E, fn(&E) -> bool, Infallible, H> {
+    pub(crate) fn new(operation: F, backoff: B) -> Self {
+        RetryBuilder {
+            operation,
+            backoff,
+            when: None,
+            hooks: H::default(),
+            keep_rejected: None,
+            max_elapsed: None,
+            #[cfg(feature = "std")]
+            deadline: None,
+            hints: None,
+            collect_errors: false,
+            budget: None,
+            #[cfg(feature = "std")]
+            cancel: None,
+            #[cfg(feature = "std")]
+            breaker: None,
+            _phantom: core::marker::PhantomData,
+        }
+    }
 }
 
-impl<F, B, T, E, W> RetryBuilder<F, B, T, E, W>
+impl<'a, F, B, T, E, W, V> RetryBuilder<'a, F, B, T, E, W, V, LocalHooks<'a, T, E>>
 where
-    F: FnMut() -> Result<T, E>,
     B: BackoffStrategy,
     W: Fn(&E) -> bool,
 {
-    /// Add a conditional predicate that determines if an error should trigger retry
+    /// Route each error through a [`Classify`] implementation
     ///
-    /// Only errors where `predicate(&error)` returns `true` will be retried.
-    /// Errors that don't match the predicate are returned immediately without retry.
+    /// Runs after the [`when`](Self::when) predicate. [`Classification::Fail`]
+    /// stops the loop as [`RetryErrorKind::PredicateRejected`] with
+    /// [`Rejection::Classifier`]. `RetryAfter` and `RetryWithPolicy` pick the
+    /// delay before the next attempt; a policy without a delay for that
+    /// attempt exhausts the retry. Attempt limits, stop conditions and the
+    /// elapsed-time budget still apply. Retry hints are only consulted for
+    /// [`Classification::Retry`].
     ///
     /// # Example
     ///
     /// ```rust
-    /// use chrono_machines::{Retryable, ExponentialBackoff};
+    /// use chrono_machines::classify::Classification;
+    /// use chrono_machines::{BackoffPolicy, ConstantBackoff, ExponentialBackoff, Retryable};
+    /// use core::time::Duration;
     ///
     /// #[derive(Debug)]
     /// enum MyError {
-    ///     Retryable,
+    ///     Busy,
+    ///     Throttled(Duration),
     ///     Fatal,
     /// }
     ///
     /// fn risky_operation() -> Result<String, MyError> {
     ///     // ...
-    /// #   Err(MyError::Retryable)
+    /// #   Ok("data".to_string())
     /// }
     ///
     /// # #[cfg(feature = "std")]
     /// let result = risky_operation
     ///     .retry(ExponentialBackoff::default())
-    ///     .when(|e| matches!(e, MyError::Retryable))
+    ///     .classify(|e: &MyError| match e {
+    ///         MyError::Busy => Classification::RetryWithPolicy(BackoffPolicy::from(
+    ///             ConstantBackoff::new().delay_ms(10),
+    ///         )),
+    ///         MyError::Throttled(delay) => Classification::RetryAfter(*delay),
+    ///         MyError::Fatal => Classification::Fail,
+    ///     })
     ///     .call();
     /// ```
-    pub fn when<P>(self, predicate: P) -> RetryBuilder<F, B, T, E, P>
+    pub fn classify<C>(mut self, classifier: C) -> Self
     where
-        P: Fn(&E) -> bool,
+        C: Classify<E> + 'a,
     {
-        RetryBuilder {
-            operation: self.operation,
-            backoff: self.backoff,
-            when: Some(predicate),
-            notify: self.notify,
-            on_success: self.on_success,
-            on_failure: self.on_failure,
-            _phantom_t: core::marker::PhantomData,
-            _phantom_e: core::marker::PhantomData,
-        }
+        self.hooks.classifier = Some(Box::new(classifier));
+        self
     }
 
     /// Add a notification callback that's invoked before each retry
@@ -475,9 +1400,9 @@ where
     /// ```
     pub fn notify<C>(mut self, callback: C) -> Self
     where
-        C: FnMut(&RetryContext<E>) + 'static,
+        C: FnMut(&RetryContext<E>) + 'a,
     {
-        self.notify = Some(Box::new(callback));
+        self.hooks.notify = Some(Box::new(callback));
         self
     }
 
@@ -486,213 +1411,1594 @@ where
     /// The callback receives a [`RetryContext`] with no error (error field is None).
     pub fn on_success<C>(mut self, callback: C) -> Self
     where
-        C: FnMut(&RetryContext<E>) + 'static,
+        C: FnMut(&RetryContext<E>) + 'a,
     {
-        self.on_success = Some(Box::new(callback));
+        self.hooks.on_success = Some(Box::new(callback));
         self
     }
 
     /// Execute a callback when the retry process terminates with failure.
     ///
     /// The callback receives the rich [`RetryError`] describing the failure.
+    /// A value rejected by [`retry_if_ok`](Self::retry_if_ok) is only attached
+    /// to the error returned from `call`, not to the one seen here.
     pub fn on_failure<C>(mut self, callback: C) -> Self
     where
-        C: FnMut(&RetryError<E>) + 'static,
+        C: FnMut(&RetryError<E>) + 'a,
     {
-        self.on_failure = Some(Box::new(callback));
+        self.hooks.on_failure = Some(Box::new(callback));
         self
     }
 
-    /// Execute the retry operation with blocking sleep (requires `std` feature)
-    ///
-    /// Runs the operation synchronously, retrying with blocking sleep between attempts.
-    ///
-    /// # Returns
+    /// Substitute a value when the retry process terminates with failure.
     ///
-    /// The final result after all retry attempts (success or final error)
+    /// Called with the final [`RetryError`] after [`on_failure`](Self::on_failure),
+    /// whatever ended the loop: exhaustion, a rejection, the deadline or
+    /// cancellation. `Ok` becomes the result of `call`, and the outcome
+    /// reports [`used_fallback`](RetryOutcome::used_fallback). On `Err` the
+    /// original [`RetryError`] is returned; the fallback's error is dropped so
+    /// it can't mask the failure that led there.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use chrono_machines::{Retryable, ExponentialBackoff};
+    /// use chrono_machines::{ExponentialBackoff, Retryable};
     ///
-    /// fn fetch_data() -> Result<String, std::io::Error> {
+    /// fn fetch_price() -> Result<u64, std::io::Error> {
     ///     // ...
-    /// #   Ok("data".to_string())
+    /// #   Err(std::io::Error::other("upstream unavailable"))
     /// }
     ///
+    /// let cached_price = 42;
     /// # #[cfg(feature = "std")]
-    /// let outcome = fetch_data
-    ///     .retry(ExponentialBackoff::default())
+    /// # {
+    /// let outcome = fetch_price
+    ///     .retry(ExponentialBackoff::new().base_delay_ms(1).max_attempts(2))
+    ///     .fallback(move |_err| Ok(cached_price))
     ///     .call()?;
-    /// println!("Succeeded after {} attempts", outcome.attempts());
+    /// assert!(outcome.used_fallback());
+    /// assert_eq!(outcome.into_inner(), 42);
+    /// # }
     /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
     /// ```
-    #[cfg(feature = "std")]
-    pub fn call(self) -> Result<RetryOutcome<T>, RetryError<E>> {
-        use crate::sleep::StdSleeper;
-        self.call_with_sleeper(StdSleeper)
+    pub fn fallback<C>(mut self, fallback: C) -> Self
+    where
+        C: FnMut(&RetryError<E>) -> Result<T, E> + 'a,
+    {
+        self.hooks.fallback = Some(Box::new(fallback));
+        self
     }
 
-    /// Execute the retry operation with a custom sleeper
+    /// Use a custom [`Clock`] to measure elapsed time against the budget
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + 'a,
+    {
+        self.hooks.clock = Some(Box::new(clock));
+        self
+    }
+
+    /// Decide when to give up with a [`StopCondition`] instead of the
+    /// strategy's attempt limit
     ///
-    /// This low-level method allows providing a custom sleep implementation,
-    /// enabling support for async runtimes, embedded systems, or testing.
+    /// The backoff strategy still supplies the delays. Once it runs out of
+    /// them, the delay of its last retry is repeated until the condition
+    /// stops the loop, which then fails with [`RetryErrorKind::Exhausted`].
+    /// An elapsed-time budget from [`max_elapsed`](Self::max_elapsed) keeps
+    /// applying on top.
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `sleeper` - Implementation of the `Sleeper` trait
+    /// ```rust
+    /// use chrono_machines::stop::{self, StopConditionExt};
+    /// use chrono_machines::{ExponentialBackoff, Retryable};
+    /// use core::time::Duration;
     ///
-    /// # Returns
+    /// fn fetch_data() -> Result<String, std::io::Error> {
+    ///     // ...
+    /// #   Ok("data".to_string())
+    /// }
     ///
-    /// The final result after all retry attempts
+    /// # #[cfg(feature = "std")]
+    /// let result = fetch_data
+    ///     .retry(ExponentialBackoff::default())
+    ///     .stop_when(stop::after_attempts(10).or(stop::after_delay(Duration::from_secs(5))))
+    ///     .call();
+    /// ```
+    pub fn stop_when<S>(mut self, condition: S) -> Self
+    where
+        S: StopCondition + 'a,
+    {
+        self.hooks.stop = Some(Box::new(condition));
+        self
+    }
+}
+
+impl<'a, F, B, T, E, W, V> RetryBuilder<'a, F, B, T, E, W, V, SendHooks<'a, T, E>>
+where
+    B: BackoffStrategy,
+    W: Fn(&E) -> bool,
+{
+    /// Route each error through a `Send` [`Classify`] implementation
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn classify<C>(mut self, classifier: C) -> Self
+    where
+        C: Classify<E> + Send + 'a,
+    {
+        self.hooks.classifier = Some(Box::new(classifier));
+        self
+    }
+
+    /// Add a `Send` callback that's invoked before each retry
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn notify<C>(mut self, callback: C) -> Self
+    where
+        C: FnMut(&RetryContext<E>) + Send + 'a,
+    {
+        self.hooks.notify = Some(Box::new(callback));
+        self
+    }
+
+    /// Execute a `Send` callback after a successful attempt
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn on_success<C>(mut self, callback: C) -> Self
+    where
+        C: FnMut(&RetryContext<E>) + Send + 'a,
+    {
+        self.hooks.on_success = Some(Box::new(callback));
+        self
+    }
+
+    /// Execute a `Send` callback when the retry process terminates with failure
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn on_failure<C>(mut self, callback: C) -> Self
+    where
+        C: FnMut(&RetryError<E>) + Send + 'a,
+    {
+        self.hooks.on_failure = Some(Box::new(callback));
+        self
+    }
+
+    /// Substitute a value from a `Send` fallback when the retry process terminates with failure
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn fallback<C>(mut self, fallback: C) -> Self
+    where
+        C: FnMut(&RetryError<E>) -> Result<T, E> + Send + 'a,
+    {
+        self.hooks.fallback = Some(Box::new(fallback));
+        self
+    }
+
+    /// Use a `Send` [`Clock`] to measure elapsed time against the budget
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + Send + 'a,
+    {
+        self.hooks.clock = Some(Box::new(clock));
+        self
+    }
+
+    /// Decide when to give up with a `Send` [`StopCondition`]
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn stop_when<S>(mut self, condition: S) -> Self
+    where
+        S: StopCondition + Send + 'a,
+    {
+        self.hooks.stop = Some(Box::new(condition));
+        self
+    }
+}
+
+impl<'a, F, B, T, E, W, V, H> RetryBuilder<'a, F, B, T, E, W, V, H>
+where
+    B: BackoffStrategy,
+    W: Fn(&E) -> bool,
+{
+    /// Add a conditional predicate that determines if an error should trigger retry
+    ///
+    /// Only errors where `predicate(&error)` returns `true` will be retried.
+    /// Errors that don't match the predicate are returned immediately without retry.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use chrono_machines::{Retryable, ExponentialBackoff, sleep::{FnSleeper, Sleeper}};
+    /// use chrono_machines::{Retryable, ExponentialBackoff};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyError {
+    ///     Retryable,
+    ///     Fatal,
+    /// }
+    ///
+    /// fn risky_operation() -> Result<String, MyError> {
+    ///     // ...
+    /// #   Err(MyError::Retryable)
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let result = risky_operation
+    ///     .retry(ExponentialBackoff::default())
+    ///     .when(|e| matches!(e, MyError::Retryable))
+    ///     .call();
+    /// ```
+    pub fn when<P>(self, predicate: P) -> RetryBuilder<'a, F, B, T, E, P, V, H>
+    where
+        P: Fn(&E) -> bool,
+    {
+        RetryBuilder {
+            operation: self.operation,
+            backoff: self.backoff,
+            when: Some(predicate),
+            hooks: self.hooks,
+            keep_rejected: self.keep_rejected,
+            max_elapsed: self.max_elapsed,
+            #[cfg(feature = "std")]
+            deadline: self.deadline,
+            hints: self.hints,
+            collect_errors: self.collect_errors,
+            budget: self.budget,
+            #[cfg(feature = "std")]
+            cancel: self.cancel,
+            #[cfg(feature = "std")]
+            breaker: self.breaker,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Stop retrying once `budget` has elapsed since the first attempt started
+    ///
+    /// Overrides any budget configured on the backoff strategy. A delay that
+    /// would overshoot the budget is shortened so the final attempt starts
+    /// right at the deadline; once no time remains the retry fails with
+    /// [`RetryErrorKind::DeadlineExceeded`].
+    ///
+    /// Elapsed time is read from the [`clock`](Self::clock). With `std` the
+    /// default is [`StdClock`](crate::clock::StdClock); without `std` and
+    /// without a clock, only time spent sleeping is counted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{ExponentialBackoff, Retryable};
+    /// use core::time::Duration;
     ///
     /// fn fetch_data() -> Result<String, std::io::Error> {
-    ///     Ok("data".to_string())
+    ///     // ...
+    /// #   Ok("data".to_string())
     /// }
     ///
-    /// fn custom_sleep(ms: u64) {
-    ///     // Custom sleep implementation
-    /// #   std::thread::sleep(std::time::Duration::from_millis(ms));
+    /// # #[cfg(feature = "std")]
+    /// let result = fetch_data
+    ///     .retry(ExponentialBackoff::default().unlimited())
+    ///     .max_elapsed(Duration::from_millis(250))
+    ///     .call();
+    /// ```
+    pub fn max_elapsed(mut self, budget: Duration) -> Self {
+        self.max_elapsed = Some(budget);
+        self
+    }
+
+    /// Stop retrying once `deadline` has passed (requires `std` feature)
+    ///
+    /// Equivalent to [`max_elapsed`](Self::max_elapsed) with the time
+    /// remaining until `deadline` when the retry starts. When both are set,
+    /// the tighter limit wins.
+    #[cfg(feature = "std")]
+    pub fn deadline(mut self, deadline: std::time::Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Prefer the delay suggested by the error's [`RetryHint`] over the strategy
+    ///
+    /// Hinted delays are capped at `cap`, so a misbehaving upstream can't
+    /// park the caller indefinitely. [`RetryAdvice::DoNotRetry`] fails
+    /// immediately with [`Rejection::Hint`]. Errors without a hint use the
+    /// strategy's delay; attempt limits, stop conditions and the elapsed-time
+    /// budget apply either way.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::hint::{RetryAdvice, RetryHint};
+    /// use chrono_machines::{ExponentialBackoff, Retryable};
+    /// use core::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// struct RateLimited {
+    ///     retry_after: Duration,
     /// }
     ///
-    /// let outcome = fetch_data
+    /// impl RetryHint for RateLimited {
+    ///     fn retry_hint(&self) -> Option<RetryAdvice> {
+    ///         Some(RetryAdvice::RetryAfter(self.retry_after))
+    ///     }
+    /// }
+    ///
+    /// fn call_api() -> Result<String, RateLimited> {
+    ///     // ...
+    /// #   Ok("data".to_string())
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let result = call_api
     ///     .retry(ExponentialBackoff::default())
-    ///     .call_with_sleeper(FnSleeper(custom_sleep))?;
-    /// println!("Total delay {}ms", outcome.cumulative_delay_ms());
-    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+    ///     .respect_retry_hints(Duration::from_secs(60))
+    ///     .call();
+    /// ```
+    pub fn respect_retry_hints(mut self, cap: Duration) -> Self
+    where
+        E: RetryHint,
+    {
+        self.hints = Some(HintPolicy::new(cap));
+        self
+    }
+
+    /// Use retry hints as configured elsewhere, e.g. on a [`Retrier`](crate::retrier::Retrier).
+    pub(crate) fn hint_policy(mut self, hints: Option<HintPolicy<E>>) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Keep the error of every retried attempt
+    ///
+    /// Each error is stored with its attempt number, the delay that followed
+    /// it and when it happened, and exposed through [`RetryError::history`]
+    /// or [`RetryOutcome::history`]. The final error stays the
+    /// [`cause`](RetryError::cause). Off by default, since it holds on to
+    /// every error for the whole run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{ConstantBackoff, Retryable};
+    ///
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// let err = (|| Err::<(), _>("connection reset"))
+    ///     .retry(ConstantBackoff::new().delay_ms(1).max_attempts(3))
+    ///     .collect_errors()
+    ///     .call()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.history().len(), 2);
+    /// assert!(err.history().iter().all(|record| record.error == "connection reset"));
+    /// # }
+    /// ```
+    pub fn collect_errors(mut self) -> Self {
+        self.collect_errors = true;
+        self
+    }
+
+    /// Abort the retry loop when `token` is cancelled (requires `std` feature)
+    ///
+    /// Cancelling interrupts the pending sleep and prevents further attempts;
+    /// an attempt already running is left to finish. The loop then fails with
+    /// [`RetryErrorKind::Cancelled`] and the last error as its cause, and the
+    /// reported cumulative delay includes the interrupted sleep.
+    ///
+    /// [`call`](Self::call) and [`call_async`](Self::call_async) wake up as
+    /// soon as the token fires. A custom blocking sleeper can't be interrupted
+    /// and is checked once it returns; use [`CancellationToken::sleeper`] to
+    /// get an interruptible one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::cancel::CancellationToken;
+    /// use chrono_machines::{ExponentialBackoff, Retryable};
+    ///
+    /// fn fetch_data() -> Result<String, std::io::Error> {
+    ///     // ...
+    /// #   Ok("data".to_string())
+    /// }
+    ///
+    /// let shutdown = CancellationToken::new();
+    /// // Keep a clone in the shutdown handler and call `cancel()` on it
+    /// let result = fetch_data
+    ///     .retry(ExponentialBackoff::default())
+    ///     .cancel_on(shutdown.clone())
+    ///     .call();
     /// ```
     #[cfg(feature = "std")]
-    pub fn call_with_sleeper<S: Sleeper>(
-        self,
-        sleeper: S,
-    ) -> Result<RetryOutcome<T>, RetryError<E>> {
-        let rng: StdRng = rand::make_rng();
-        self.call_with_sleeper_and_rng(sleeper, rng)
+    pub fn cancel_on(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Draw retries from a [`RetryBudget`] shared with other calls
+    ///
+    /// Every retry withdraws a token from the budget and a successful call
+    /// deposits part of one. When the budget is empty the loop stops instead
+    /// of scheduling the next attempt, failing with
+    /// [`RetryErrorKind::BudgetExhausted`] and the last error as its cause.
+    /// Attach clones of the same budget to every call to a dependency so
+    /// retries stay a bounded share of its traffic during an outage.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::budget::RetryBudget;
+    /// use chrono_machines::{ExponentialBackoff, Retryable};
+    ///
+    /// fn fetch_data() -> Result<String, std::io::Error> {
+    ///     // ...
+    /// #   Ok("data".to_string())
+    /// }
+    ///
+    /// // Shared by every call to the same upstream
+    /// let budget = RetryBudget::new(10, 0.2);
+    /// let result = fetch_data
+    ///     .retry(ExponentialBackoff::default())
+    ///     .budget(budget.clone())
+    ///     .call();
+    /// ```
+    pub fn budget(mut self, budget: RetryBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Consult `breaker` before every attempt (requires `std` feature)
+    ///
+    /// Each attempt asks the breaker for permission and reports whether it
+    /// returned `Ok` or `Err`; every error counts as a failure, whether or
+    /// not it is retried. When the breaker rejects an attempt the operation
+    /// is not invoked and the loop fails with [`RetryErrorKind::CircuitOpen`]
+    /// and the last error, if any, as its cause. A fallback still applies.
+    /// Share clones of one breaker between all calls to a dependency.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::circuit::CircuitBreaker;
+    /// use chrono_machines::{ConstantBackoff, ExponentialBackoff, Retryable};
+    ///
+    /// fn fetch_data() -> Result<String, std::io::Error> {
+    ///     // ...
+    /// #   Ok("data".to_string())
+    /// }
+    ///
+    /// // Shared by every call to the same upstream
+    /// let breaker = CircuitBreaker::new(ConstantBackoff::new().delay_ms(30_000));
+    /// let result = fetch_data
+    ///     .retry(ExponentialBackoff::default())
+    ///     .circuit_breaker(breaker.clone())
+    ///     .call();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+}
+
+impl<'a, F, B, T, E, W, V, H: RunHooks<T, E>> RetryBuilder<'a, F, B, T, E, W, V, H>
+where
+    B: BackoffStrategy,
+    W: Fn(&E) -> bool,
+{
+    /// Whether the cancellation token, if any, has fired.
+    fn is_cancelled(&self) -> bool {
+        #[cfg(feature = "std")]
+        if let Some(ref token) = self.cancel {
+            return token.is_cancelled();
+        }
+
+        false
+    }
+
+    /// Ask the circuit breaker, if any, to let the next attempt through.
+    ///
+    /// Returns `None` when the breaker rejects the attempt.
+    #[cfg(feature = "std")]
+    fn circuit_permit(&self) -> Option<Option<CircuitPermit>> {
+        match self.breaker {
+            Some(ref breaker) => breaker.try_acquire().map(Some),
+            None => Some(None),
+        }
+    }
+
+    /// Without `std` there is no circuit breaker to ask.
+    #[cfg(not(feature = "std"))]
+    fn circuit_permit(&self) -> Option<()> {
+        Some(())
+    }
+
+    /// Build a terminal [`RetryError`], firing the `on_failure` callback and
+    /// consulting the fallback if present.
+    ///
+    /// Both hooks run before the last rejected `Ok` value is attached.
+    fn fail(
+        &mut self,
+        kind: RetryErrorKind,
+        state: &mut RunState<E, V, H::Clock>,
+        cause: Option<E>,
+    ) -> Result<RetryOutcome<T, E>, RetryError<E, V>> {
+        let retry_error = RetryError::new(
+            kind,
+            state.attempt,
+            state.max_attempts,
+            state.cumulative_delay,
+            cause,
+        )
+        .with_history(state.take_history());
+        if let Some(callback) = self.hooks.on_failure() {
+            callback(&retry_error);
+        }
+        // A failing fallback must not mask the original error
+        if let Some(fallback) = self.hooks.fallback()
+            && let Ok(value) = fallback(&retry_error)
+        {
+            let outcome = RetryOutcome::new(
+                value,
+                state.attempt,
+                state.cumulative_delay,
+                retry_error.history,
+            );
+            return Ok(outcome.with_fallback());
+        }
+        Err(retry_error.with_last_value(state.last_value.take()))
+    }
+
+    /// Fail a run before its next attempt starts, e.g. because the sleep
+    /// was cancelled or the circuit is open.
+    fn finish_before_attempt(
+        &mut self,
+        kind: RetryErrorKind,
+        state: &mut RunState<E, V, H::Clock>,
+        cause: Option<E>,
+    ) -> Result<RetryOutcome<T, E>, RetryError<E, V>> {
+        // The next attempt never started
+        state.attempt = state.attempt.saturating_sub(1);
+        self.fail(kind, state, cause)
+    }
+
+    /// Elapsed-time budget from the builder, the deadline, or the backoff strategy.
+    fn time_budget(&self) -> Option<Duration> {
+        let budget = self.max_elapsed.or_else(|| self.backoff.max_elapsed());
+
+        #[cfg(feature = "std")]
+        if let Some(deadline) = self.deadline {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            return Some(budget.map_or(remaining, |budget| budget.min(remaining)));
+        }
+
+        budget
+    }
+
+    /// Resolve the time budget and clock, and set up state for a new run.
+    fn start_run(&mut self) -> RunState<E, V, H::Clock> {
+        let max_attempts = match self.hooks.stop() {
+            Some(stop) => stop.max_attempts(),
+            None => self.backoff.max_attempts(),
+        };
+
+        let budget = self.time_budget();
+        let mut state = RunState {
+            attempt: 1,
+            max_attempts,
+            cumulative_delay: Duration::ZERO,
+            previous_delay: None,
+            last_value: None,
+            budget,
+            clock: self.hooks.take_clock(),
+            #[cfg(feature = "std")]
+            std_clock: crate::clock::StdClock::new(),
+            start: Duration::ZERO,
+            history: self.collect_errors.then(Vec::new),
+            failed_at: Duration::ZERO,
+        };
+        state.start = state.now().unwrap_or_default();
+        state
+    }
+
+    /// Delay chosen by the strategy, or by the policy the classifier routed to
+    fn strategy_delay<R: rand::Rng>(
+        &self,
+        attempt: u32,
+        routed_policy: Option<&BackoffPolicy>,
+        previous_delay: Option<Duration>,
+        rng: &mut R,
+    ) -> Option<Duration> {
+        if let Some(policy) = routed_policy {
+            return policy.next_delay_duration(attempt, previous_delay, rng);
+        }
+        if self.hooks.stop().is_none() {
+            return self
+                .backoff
+                .next_delay_duration(attempt, previous_delay, rng);
+        }
+
+        // The stop condition replaces the strategy's limit: past it, repeat
+        // the delay of its last retry, or the previous delay
+        let delay_attempt = match self.backoff.max_attempts() {
+            Some(strategy_max) => attempt.min(strategy_max.saturating_sub(1)),
+            None => attempt,
+        };
+        self.backoff
+            .next_delay_duration(delay_attempt, previous_delay, rng)
+            .or(previous_delay)
+    }
+
+    /// Decide what follows an attempt: finish, or sleep and try again.
+    ///
+    /// Shared by the blocking and async loops so both run the same hooks.
+    fn step<R: rand::Rng>(
+        &mut self,
+        state: &mut RunState<E, V, H::Clock>,
+        result: Result<T, E>,
+        permit: AttemptPermit,
+        rng: &mut R,
+    ) -> Step<T, E, V> {
+        let attempt = state.attempt;
+        let cumulative_delay = state.cumulative_delay;
+        report_to_circuit(permit, result.is_err());
+
+        let error = match result {
+            Ok(value) => {
+                let rejected = self
+                    .hooks
+                    .retry_if_ok()
+                    .is_some_and(|predicate| predicate(&value));
+                if !rejected {
+                    // Invoke on_success callback with context
+                    if let Some(callback) = self.hooks.on_success() {
+                        let ctx = RetryContext {
+                            attempt,
+                            next_delay_ms: None,
+                            next_delay: None,
+                            delay_source: None,
+                            cumulative_delay_ms: duration_as_ms(cumulative_delay),
+                            cumulative_delay,
+                            error: None,
+                        };
+                        callback(&ctx);
+                    }
+                    if let Some(ref budget) = self.budget {
+                        budget.deposit();
+                    }
+                    let outcome =
+                        RetryOutcome::new(value, attempt, cumulative_delay, state.take_history());
+                    return Step::Finish(Ok(outcome));
+                }
+
+                // Unacceptable value: keep it for the error and retry
+                state.last_value = self.keep_rejected.map(|keep| keep(value));
+                None
+            }
+            Err(error) => Some(error),
+        };
+        if state.history.is_some() {
+            state.failed_at = state.elapsed();
+        }
+
+        // Don't schedule another attempt once cancelled
+        if self.is_cancelled() {
+            return Step::Finish(self.fail(RetryErrorKind::Cancelled, state, error));
+        }
+
+        // Check if this error should be retried
+        if let Some(ref predicate) = self.when
+            && let Some(ref cause) = error
+            && !predicate(cause)
+        {
+            // Error doesn't match predicate, fail immediately
+            return Step::Finish(self.fail(
+                RetryErrorKind::PredicateRejected(Rejection::Predicate),
+                state,
+                error,
+            ));
+        }
+
+        // Route the error through the classifier
+        let mut chosen_delay = None;
+        let mut routed_policy = None;
+        if let Some(classifier) = self.hooks.classifier()
+            && let Some(ref cause) = error
+        {
+            match classifier.classify(cause) {
+                Classification::Retry => {}
+                Classification::RetryAfter(delay) => {
+                    chosen_delay = Some((delay, DelaySource::Classifier));
+                }
+                Classification::RetryWithPolicy(policy) => {
+                    routed_policy = Some(policy);
+                }
+                Classification::Fail => {
+                    return Step::Finish(self.fail(
+                        RetryErrorKind::PredicateRejected(Rejection::Classifier),
+                        state,
+                        error,
+                    ));
+                }
+            }
+        }
+
+        // Ask the error for a suggested delay
+        if let Some(ref hints) = self.hints
+            && let Some(ref cause) = error
+            && chosen_delay.is_none()
+            && routed_policy.is_none()
+        {
+            match (hints.advice)(cause) {
+                Some(RetryAdvice::DoNotRetry) => {
+                    return Step::Finish(self.fail(
+                        RetryErrorKind::PredicateRejected(Rejection::Hint),
+                        state,
+                        error,
+                    ));
+                }
+                Some(RetryAdvice::RetryAfter(delay)) => {
+                    chosen_delay = Some((delay.min(hints.cap), DelaySource::Hint));
+                }
+                None => {}
+            }
+        }
+
+        // Check if we have retries remaining
+        let exhausted = match self.hooks.stop() {
+            Some(stop) => stop.should_stop(&StopState {
+                attempt,
+                elapsed: state.elapsed(),
+                cumulative_delay,
+            }),
+            None => !self.backoff.should_retry(attempt),
+        };
+        if exhausted {
+            return Step::Finish(self.fail(RetryErrorKind::Exhausted, state, error));
+        }
+
+        // Calculate delay
+        let previous_delay = state.previous_delay;
+        let (mut delay, delay_source) = match chosen_delay {
+            // The error said when to come back, so the strategy isn't asked
+            Some(chosen) => chosen,
+            None => {
+                let next_delay =
+                    self.strategy_delay(attempt, routed_policy.as_ref(), previous_delay, rng);
+                let Some(delay) = next_delay else {
+                    // Backoff says no more retries
+                    return Step::Finish(self.fail(RetryErrorKind::Exhausted, state, error));
+                };
+                match routed_policy {
+                    Some(_) => (delay, DelaySource::Classifier),
+                    None => (delay, DelaySource::Strategy),
+                }
+            }
+        };
+
+        // Clip the delay to the remaining time budget
+        if let Some(budget) = state.budget {
+            let remaining = budget.saturating_sub(state.elapsed());
+            if remaining.is_zero() {
+                return Step::Finish(self.fail(RetryErrorKind::DeadlineExceeded, state, error));
+            }
+            delay = delay.min(remaining);
+        }
+
+        // Pay for the retry from the shared budget
+        if let Some(ref budget) = self.budget
+            && !budget.try_withdraw()
+        {
+            return Step::Finish(self.fail(RetryErrorKind::BudgetExhausted, state, error));
+        }
+
+        // Notify if callback is set
+        if let Some(notify) = self.hooks.notify() {
+            let ctx = RetryContext {
+                attempt,
+                next_delay_ms: Some(duration_as_ms(delay)),
+                next_delay: Some(delay),
+                delay_source: Some(delay_source),
+                cumulative_delay_ms: duration_as_ms(cumulative_delay),
+                cumulative_delay,
+                error: error.as_ref(),
+            };
+            notify(&ctx);
+        }
+
+        // Account for the sleep the caller performs before the next attempt
+        state.cumulative_delay = cumulative_delay.saturating_add(delay);
+        state.previous_delay = Some(delay);
+        state.attempt = attempt.saturating_add(1);
+        Step::Retry(delay, error)
+    }
+}
+
+impl<'a, F, B, T, E, W, V, H: RunHooks<T, E>> RetryBuilder<'a, F, B, T, E, W, V, H>
+where
+    F: Operation<T, E>,
+    B: BackoffStrategy,
+    W: Fn(&E) -> bool,
+{
+    /// Execute the retry operation with blocking sleep (requires `std` feature)
+    ///
+    /// Runs the operation synchronously, retrying with blocking sleep between attempts.
+    /// The sleep ends early when the token from [`cancel_on`](Self::cancel_on)
+    /// is cancelled.
+    ///
+    /// # Returns
+    ///
+    /// The final result after all retry attempts (success or final error)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{Retryable, ExponentialBackoff};
+    ///
+    /// fn fetch_data() -> Result<String, std::io::Error> {
+    ///     // ...
+    /// #   Ok("data".to_string())
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// let outcome = fetch_data
+    ///     .retry(ExponentialBackoff::default())
+    ///     .call()?;
+    /// println!("Succeeded after {} attempts", outcome.attempts());
+    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn call(self) -> Result<RetryOutcome<T, E>, RetryError<E, V>> {
+        use crate::sleep::StdSleeper;
+        match self.cancel {
+            Some(ref token) => {
+                let sleeper = token.sleeper();
+                self.call_with_sleeper(sleeper)
+            }
+            None => self.call_with_sleeper(StdSleeper),
+        }
+    }
+
+    /// Execute the retry operation with a custom sleeper
+    ///
+    /// This low-level method allows providing a custom sleep implementation,
+    /// enabling support for async runtimes, embedded systems, or testing.
+    ///
+    /// # Arguments
+    ///
+    /// * `sleeper` - Implementation of the `Sleeper` trait
+    ///
+    /// # Returns
+    ///
+    /// The final result after all retry attempts
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{Retryable, ExponentialBackoff, sleep::{FnSleeper, Sleeper}};
+    ///
+    /// fn fetch_data() -> Result<String, std::io::Error> {
+    ///     Ok("data".to_string())
+    /// }
+    ///
+    /// fn custom_sleep(ms: u64) {
+    ///     // Custom sleep implementation
+    /// #   std::thread::sleep(std::time::Duration::from_millis(ms));
+    /// }
+    ///
+    /// let outcome = fetch_data
+    ///     .retry(ExponentialBackoff::default())
+    ///     .call_with_sleeper(FnSleeper(custom_sleep))?;
+    /// println!("Total delay {}ms", outcome.cumulative_delay_ms());
+    /// # Ok::<(), chrono_machines::RetryError<std::io::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn call_with_sleeper<S: Sleeper>(
+        self,
+        sleeper: S,
+    ) -> Result<RetryOutcome<T, E>, RetryError<E, V>> {
+        let rng: StdRng = rand::make_rng();
+        self.call_with_sleeper_and_rng(sleeper, rng)
+    }
+
+    /// Execute the retry operation with a custom sleeper and a caller-supplied RNG.
+    ///
+    /// The `no_std` entry point: callers provide their own [`rand::Rng`] instead
+    /// of relying on the `std`-only `make_rng`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sleeper` - Implementation of the [`Sleeper`] trait
+    /// * `rng` - Random number generator used for jitter
+    pub fn call_with_sleeper_and_rng<S: Sleeper, R: rand::Rng>(
+        mut self,
+        sleeper: S,
+        mut rng: R,
+    ) -> Result<RetryOutcome<T, E>, RetryError<E, V>> {
+        let mut state = self.start_run();
+        let Some(mut permit) = self.circuit_permit() else {
+            return self.finish_before_attempt(RetryErrorKind::CircuitOpen, &mut state, None);
+        };
+        let mut last_error = None;
+        loop {
+            let info = state.attempt_info(last_error.as_ref());
+            // A panic drops `permit`, which reports the attempt as failed
+            let result = self.operation.run(&info);
+            match self.step(&mut state, result, permit, &mut rng) {
+                Step::Retry(delay, error) => {
+                    sleeper.sleep(delay);
+                    if self.is_cancelled() {
+                        return self.finish_before_attempt(
+                            RetryErrorKind::Cancelled,
+                            &mut state,
+                            error,
+                        );
+                    }
+                    let Some(next) = self.circuit_permit() else {
+                        return self.finish_before_attempt(
+                            RetryErrorKind::CircuitOpen,
+                            &mut state,
+                            error,
+                        );
+                    };
+                    permit = next;
+                    last_error = state.record(error);
+                }
+                Step::Finish(result) => return result,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, F, B, T, E, W, V, H: RunHooks<T, E>> RetryBuilder<'a, F, B, T, E, W, V, H>
+where
+    F: AsyncOperation<T, E>,
+    B: BackoffStrategy,
+    W: Fn(&E) -> bool,
+{
+    /// Execute the retry operation asynchronously (requires `async` and `std` features)
+    ///
+    /// Awaits the operation and waits between attempts with `sleeper`, so no
+    /// thread is blocked. Hooks, stop rules and the returned
+    /// [`RetryOutcome`]/[`RetryError`] behave exactly as with
+    /// [`call`](Self::call).
+    ///
+    /// # Arguments
+    ///
+    /// * `sleeper` - Implementation of the [`AsyncSleeper`] trait
+    #[cfg(feature = "std")]
+    pub async fn call_async<S: AsyncSleeper>(
+        self,
+        sleeper: S,
+    ) -> Result<RetryOutcome<T, E>, RetryError<E, V>> {
+        let rng: StdRng = rand::make_rng();
+        self.call_async_with_rng(sleeper, rng).await
+    }
+
+    /// Execute the retry operation asynchronously with a caller-supplied RNG.
+    ///
+    /// The `no_std` async entry point, e.g. for firmware using
+    /// [`EmbassySleeper`](crate::sleep::EmbassySleeper).
+    ///
+    /// # Arguments
+    ///
+    /// * `sleeper` - Implementation of the [`AsyncSleeper`] trait
+    /// * `rng` - Random number generator used for jitter
+    pub async fn call_async_with_rng<S: AsyncSleeper, R: rand::Rng>(
+        mut self,
+        sleeper: S,
+        mut rng: R,
+    ) -> Result<RetryOutcome<T, E>, RetryError<E, V>> {
+        let mut state = self.start_run();
+        let Some(mut permit) = self.circuit_permit() else {
+            return self.finish_before_attempt(RetryErrorKind::CircuitOpen, &mut state, None);
+        };
+        let mut last_error = None;
+        loop {
+            let info = state.attempt_info(last_error.as_ref());
+            // Dropping the future drops `permit`, which reports the attempt as failed
+            let result = self.operation.run(&info).await;
+            match self.step(&mut state, result, permit, &mut rng) {
+                Step::Retry(delay, error) => {
+                    #[cfg(feature = "std")]
+                    sleep_async(&sleeper, delay, self.cancel.as_ref()).await;
+                    #[cfg(not(feature = "std"))]
+                    sleeper.sleep(delay).await;
+                    if self.is_cancelled() {
+                        return self.finish_before_attempt(
+                            RetryErrorKind::Cancelled,
+                            &mut state,
+                            error,
+                        );
+                    }
+                    let Some(next) = self.circuit_permit() else {
+                        return self.finish_before_attempt(
+                            RetryErrorKind::CircuitOpen,
+                            &mut state,
+                            error,
+                        );
+                    };
+                    permit = next;
+                    last_error = state.record(error);
+                }
+                Step::Finish(result) => return result,
+            }
+        }
+    }
+}
+
+/// Sleep with `sleeper`, racing the cancellation token when one is set.
+///
+/// Takes the token rather than the builder so the retry future only needs
+/// the builder to be `Send`, not `Sync`.
+#[cfg(all(feature = "async", feature = "std"))]
+async fn sleep_async<S: AsyncSleeper>(
+    sleeper: &S,
+    delay: Duration,
+    cancel: Option<&CancellationToken>,
+) {
+    match cancel {
+        Some(token) => {
+            token.run_until_cancelled(sleeper.sleep(delay)).await;
+        }
+        None => sleeper.sleep(delay).await,
+    }
+}
+
+impl<'a, F, B, T, E, W, H> RetryBuilder<'a, F, B, T, E, W, Infallible, H> {
+    /// Switch to keeping values rejected by `retry_if_ok` for the error.
+    fn keep_rejected_values(self) -> RetryBuilder<'a, F, B, T, E, W, T, H> {
+        RetryBuilder {
+            operation: self.operation,
+            backoff: self.backoff,
+            when: self.when,
+            hooks: self.hooks,
+            keep_rejected: Some(|value| value),
+            max_elapsed: self.max_elapsed,
+            #[cfg(feature = "std")]
+            deadline: self.deadline,
+            hints: self.hints,
+            collect_errors: self.collect_errors,
+            budget: self.budget,
+            #[cfg(feature = "std")]
+            cancel: self.cancel,
+            #[cfg(feature = "std")]
+            breaker: self.breaker,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, F, B, T, E, W> RetryBuilder<'a, F, B, T, E, W, Infallible, LocalHooks<'a, T, E>>
+where
+    B: BackoffStrategy,
+    W: Fn(&E) -> bool,
+{
+    /// Retry `Ok` values for which `predicate` returns `true`
+    ///
+    /// A rejected value goes through the backoff loop like an error: attempt
+    /// limits, stop conditions and the elapsed-time budget apply, while the
+    /// `when` predicate, classifier and retry hints only look at errors.
+    /// Callbacks see the rejected attempt with no error. When retries run out
+    /// the returned [`RetryError`] holds the most recent rejected value in
+    /// [`last_value`](RetryError::last_value).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono_machines::{ConstantBackoff, Retryable};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum JobStatus {
+    ///     Pending,
+    ///     Done(u32),
+    /// }
+    ///
+    /// fn poll_job() -> Result<JobStatus, std::io::Error> {
+    ///     // ...
+    /// #   Ok(JobStatus::Pending)
+    /// }
+    ///
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// let result = poll_job
+    ///     .retry(ConstantBackoff::new().delay_ms(1).max_attempts(3))
+    ///     .retry_if_ok(|status| *status == JobStatus::Pending)
+    ///     .call();
+    ///
+    /// if let Err(err) = result {
+    ///     assert_eq!(err.last_value(), Some(&JobStatus::Pending));
+    /// }
+    /// # }
+    /// ```
+    pub fn retry_if_ok<Q>(
+        self,
+        predicate: Q,
+    ) -> RetryBuilder<'a, F, B, T, E, W, T, LocalHooks<'a, T, E>>
+    where
+        Q: Fn(&T) -> bool + 'a,
+    {
+        let mut builder = self.keep_rejected_values();
+        builder.hooks.retry_if_ok = Some(Box::new(predicate));
+        builder
+    }
+}
+
+impl<'a, F, B, T, E, W> RetryBuilder<'a, F, B, T, E, W, Infallible, SendHooks<'a, T, E>>
+where
+    B: BackoffStrategy,
+    W: Fn(&E) -> bool,
+{
+    /// Retry `Ok` values for which a `Send` `predicate` returns `true`
+    ///
+    /// See the [`LocalHooks`] version of this method.
+    pub fn retry_if_ok<Q>(
+        self,
+        predicate: Q,
+    ) -> RetryBuilder<'a, F, B, T, E, W, T, SendHooks<'a, T, E>>
+    where
+        Q: Fn(&T) -> bool + Send + 'a,
+    {
+        let mut builder = self.keep_rejected_values();
+        builder.hooks.retry_if_ok = Some(Box::new(predicate));
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backoff::{ConstantBackoff, ExponentialBackoff};
+    use crate::sleep::FnSleeper;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestError {
+        Retryable,
+        Fatal,
+    }
+
+    #[test]
+    fn test_retry_success_on_first_attempt() {
+        fn always_succeeds() -> Result<i32, TestError> {
+            Ok(42)
+        }
+
+        let result = always_succeeds
+            .retry(ExponentialBackoff::default())
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 1);
+        assert_eq!(outcome.into_inner(), 42);
+    }
+
+    #[test]
+    fn test_retry_success_after_failures() {
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 2 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(42)
+            }
+        };
+
+        let result = operation
+            .retry(ExponentialBackoff::default().max_attempts(3))
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 3);
+        assert_eq!(outcome.into_inner(), 42);
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_exhausted() {
+        fn always_fails() -> Result<i32, TestError> {
+            Err(TestError::Retryable)
+        }
+
+        let result = always_fails
+            .retry(ExponentialBackoff::default().max_attempts(3))
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let err = result.expect_err("retry should exhaust");
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3);
+        assert_eq!(err.max_attempts(), Some(3));
+        assert!(err.cumulative_delay_ms() > 0);
+        if let Some(cause) = err.cause() {
+            assert_eq!(cause, &TestError::Retryable);
+        } else {
+            panic!("expected underlying cause");
+        }
+    }
+
+    #[test]
+    fn test_retry_when_predicate() {
+        fn fails_with_fatal() -> Result<i32, TestError> {
+            Err(TestError::Fatal)
+        }
+
+        let result = fails_with_fatal
+            .retry(ExponentialBackoff::default())
+            .when(|e| matches!(e, TestError::Retryable))
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        // Fatal error should not be retried
+        let err = result.expect_err("retry should stop due to predicate");
+        assert_eq!(
+            err.kind(),
+            RetryErrorKind::PredicateRejected(Rejection::Predicate)
+        );
+        if let Some(cause) = err.cause() {
+            assert_eq!(cause, &TestError::Fatal);
+        } else {
+            panic!("expected underlying cause");
+        }
+    }
+
+    #[test]
+    fn test_retry_notify_callback() {
+        use core::cell::{Cell, RefCell};
+
+        let attempts = Cell::new(0);
+        let notify_calls = RefCell::new(Vec::new());
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 2 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(42)
+            }
+        };
+
+        let result = operation
+            .retry(ExponentialBackoff::default().max_attempts(3))
+            .notify(|ctx| {
+                // Track notify calls
+                notify_calls.borrow_mut().push((
+                    ctx.attempt,
+                    ctx.next_delay_ms,
+                    ctx.cumulative_delay_ms,
+                    ctx.error.is_some(),
+                ));
+                assert!(ctx.attempt >= 1);
+                assert!(ctx.next_delay_ms.is_some());
+                assert!(ctx.error.is_some());
+            })
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 3);
+
+        // Verify notify was called twice (for the two failures)
+        let calls = notify_calls.borrow();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].0, 1); // First attempt
+        assert_eq!(calls[1].0, 2); // Second attempt
+    }
+
+    #[test]
+    fn test_on_success_callback_invoked() {
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0);
+        let success_attempt = Cell::new(0);
+        let success_cumulative_delay = Cell::new(0);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 1 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(7)
+            }
+        };
+
+        let outcome = operation
+            .retry(
+                ExponentialBackoff::default()
+                    .jitter_factor(0.0)
+                    .max_attempts(3),
+            )
+            .on_success(|ctx| {
+                success_attempt.set(ctx.attempt);
+                success_cumulative_delay.set(ctx.cumulative_delay_ms);
+                assert!(ctx.error.is_none());
+                assert!(ctx.next_delay_ms.is_none());
+            })
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect("retry should succeed");
+
+        assert_eq!(outcome.into_inner(), 7);
+        assert_eq!(success_attempt.get(), 2);
+        // Should have some cumulative delay from the first retry
+        assert!(success_cumulative_delay.get() > 0);
+    }
+
+    #[test]
+    fn test_on_failure_callback_invoked() {
+        use core::cell::Cell;
+
+        let failure_kind = Cell::new(None);
+        let failure_cumulative_delay = Cell::new(0);
+
+        fn always_fails() -> Result<(), TestError> {
+            Err(TestError::Retryable)
+        }
+
+        let result = always_fails
+            .retry(
+                ExponentialBackoff::default()
+                    .jitter_factor(0.0)
+                    .max_attempts(2),
+            )
+            .on_failure(|err| {
+                failure_kind.set(Some(err.kind()));
+                failure_cumulative_delay.set(err.cumulative_delay_ms());
+            })
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        assert!(result.is_err());
+        assert_eq!(failure_kind.get(), Some(RetryErrorKind::Exhausted));
+        // Should have cumulative delay from retry attempt
+        assert!(failure_cumulative_delay.get() > 0);
+    }
+
+    #[test]
+    fn test_hooks_borrow_enclosing_scope() {
+        let mut retried = Vec::new();
+        let mut summary = String::new();
+
+        let result = (|| Err::<(), _>(TestError::Retryable))
+            .retry(ConstantBackoff::new().delay_ms(0).max_attempts(3))
+            .notify(|ctx| retried.push(ctx.attempt))
+            .on_failure(|err| summary = format!("{:?}", err.kind()))
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        assert!(result.is_err());
+        assert_eq!(retried, vec![1, 2]);
+        assert_eq!(summary, "Exhausted");
+    }
+
+    #[test]
+    fn test_constant_backoff_retry() {
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 1 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(42)
+            }
+        };
+
+        let result = operation
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(2))
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 2);
+        assert_eq!(outcome.into_inner(), 42);
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_retry_with_std_sleeper() {
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 1 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(42)
+            }
+        };
+
+        let start = std::time::Instant::now();
+        let result = operation
+            .retry(
+                ConstantBackoff::new()
+                    .delay_ms(10)
+                    .max_attempts(2)
+                    .jitter_factor(0.0),
+            )
+            .call();
+
+        let elapsed = start.elapsed();
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 2);
+        assert_eq!(outcome.into_inner(), 42);
+        assert!(elapsed.as_millis() >= 9); // At least one 10ms sleep
+    }
+
+    #[test]
+    fn test_retry_context_comprehensive() {
+        use core::cell::{Cell, RefCell};
+
+        let attempts = Cell::new(0);
+        let notify_contexts = RefCell::new(Vec::new());
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 3 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(42)
+            }
+        };
+
+        let result = operation
+            .retry(
+                ConstantBackoff::new()
+                    .delay_ms(100)
+                    .max_attempts(5)
+                    .jitter_factor(0.0),
+            )
+            .notify(|ctx| {
+                // Capture context for verification
+                notify_contexts.borrow_mut().push((
+                    ctx.attempt,
+                    ctx.next_delay_ms,
+                    ctx.cumulative_delay_ms,
+                ));
+
+                // Verify error is present during notify
+                assert!(ctx.error.is_some());
+                if let Some(err) = ctx.error {
+                    assert_eq!(err, &TestError::Retryable);
+                }
+            })
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 4);
+
+        // Verify notify contexts
+        let contexts = notify_contexts.borrow();
+        assert_eq!(contexts.len(), 3); // Three failures before success
+
+        // First failure
+        assert_eq!(contexts[0].0, 1);
+        assert_eq!(contexts[0].1, Some(100));
+        assert_eq!(contexts[0].2, 0); // No cumulative delay yet
+
+        // Second failure
+        assert_eq!(contexts[1].0, 2);
+        assert_eq!(contexts[1].1, Some(100));
+        assert_eq!(contexts[1].2, 100); // One delay accumulated
+
+        // Third failure
+        assert_eq!(contexts[2].0, 3);
+        assert_eq!(contexts[2].1, Some(100));
+        assert_eq!(contexts[2].2, 200); // Two delays accumulated
+    }
+
+    #[test]
+    fn test_retry_context_on_success() {
+        use core::cell::{Cell, RefCell};
+
+        let attempts = Cell::new(0);
+        let success_context = RefCell::new(None);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 2 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(100)
+            }
+        };
+
+        let result = operation
+            .retry(
+                ConstantBackoff::new()
+                    .delay_ms(50)
+                    .max_attempts(5)
+                    .jitter_factor(0.0),
+            )
+            .on_success(|ctx| {
+                success_context.borrow_mut().replace((
+                    ctx.attempt,
+                    ctx.next_delay_ms,
+                    ctx.cumulative_delay_ms,
+                    ctx.error.is_none(),
+                ));
+            })
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 3);
+        assert_eq!(outcome.cumulative_delay_ms(), 100); // 2 delays of 50ms
+
+        // Verify success context
+        let ctx = success_context.borrow();
+        assert!(ctx.is_some());
+        let (attempt, next_delay, cumulative, no_error) = ctx.unwrap();
+        assert_eq!(attempt, 3);
+        assert_eq!(next_delay, None); // No next delay on success
+        assert_eq!(cumulative, 100);
+        assert!(no_error); // Error should be None
     }
 
-    /// Execute the retry operation with a custom sleeper and a caller-supplied RNG.
-    ///
-    /// The `no_std` entry point: callers provide their own [`rand::Rng`] instead
-    /// of relying on the `std`-only `make_rng`.
-    ///
-    /// # Arguments
-    ///
-    /// * `sleeper` - Implementation of the [`Sleeper`] trait
-    /// * `rng` - Random number generator used for jitter
-    pub fn call_with_sleeper_and_rng<S: Sleeper, R: rand::Rng>(
-        mut self,
-        sleeper: S,
-        mut rng: R,
-    ) -> Result<RetryOutcome<T>, RetryError<E>> {
-        let mut attempt = 1u8;
-        let max_attempts = self.backoff.max_attempts();
-        let mut cumulative_delay_ms: u64 = 0;
+    #[test]
+    fn test_retry_context_cumulative_accuracy() {
+        use core::cell::{Cell, RefCell};
 
-        loop {
-            match (self.operation)() {
-                Ok(_value) => {
-                    // Invoke on_success callback with context
-                    if let Some(ref mut callback) = self.on_success {
-                        let ctx = RetryContext {
-                            attempt,
-                            next_delay_ms: None,
-                            cumulative_delay_ms,
-                            error: None,
-                        };
-                        callback(&ctx);
-                    }
-                    return Ok(RetryOutcome::new(_value, attempt, cumulative_delay_ms));
-                }
-                Err(error) => {
-                    // Check if this error should be retried
-                    if let Some(ref predicate) = self.when
-                        && !predicate(&error) {
-                            // Error doesn't match predicate, fail immediately
-                            return Err(finalize_failure(
-                                self.on_failure.as_mut(),
-                                RetryErrorKind::PredicateRejected,
-                                attempt,
-                                max_attempts,
-                                cumulative_delay_ms,
-                                error,
-                            ));
-                        }
-
-                    // Check if we have retries remaining
-                    if !self.backoff.should_retry(attempt) {
-                        return Err(finalize_failure(
-                            self.on_failure.as_mut(),
-                            RetryErrorKind::Exhausted,
-                            attempt,
-                            max_attempts,
-                            cumulative_delay_ms,
-                            error,
-                        ));
-                    }
+        let attempts = Cell::new(0);
+        let cumulative_progression = RefCell::new(Vec::new());
 
-                    // Calculate delay
-                    match self.backoff.delay(attempt, &mut rng) {
-                        Some(delay_ms) => {
-                            // Notify if callback is set
-                            if let Some(ref mut notify) = self.notify {
-                                let ctx = RetryContext {
-                                    attempt,
-                                    next_delay_ms: Some(delay_ms),
-                                    cumulative_delay_ms,
-                                    error: Some(&error),
-                                };
-                                notify(&ctx);
-                            }
-
-                            // Sleep before retry
-                            sleeper.sleep_ms(delay_ms);
-                            cumulative_delay_ms = cumulative_delay_ms.saturating_add(delay_ms);
-                            attempt = attempt.saturating_add(1);
-                        }
-                        None => {
-                            // Backoff says no more retries
-                            return Err(finalize_failure(
-                                self.on_failure.as_mut(),
-                                RetryErrorKind::Exhausted,
-                                attempt,
-                                max_attempts,
-                                cumulative_delay_ms,
-                                error,
-                            ));
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+            Err::<(), TestError>(TestError::Retryable)
+        };
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::backoff::{ConstantBackoff, ExponentialBackoff};
-    use crate::sleep::FnSleeper;
+        let _result = operation
+            .retry(
+                ConstantBackoff::new()
+                    .delay_ms(25)
+                    .max_attempts(4)
+                    .jitter_factor(0.0),
+            )
+            .notify(|ctx| {
+                cumulative_progression
+                    .borrow_mut()
+                    .push(ctx.cumulative_delay_ms);
+            })
+            .call_with_sleeper(FnSleeper(|_| {}));
 
-    #[derive(Debug, PartialEq)]
-    enum TestError {
-        Retryable,
-        Fatal,
+        // Verify cumulative delay progression
+        let progression = cumulative_progression.borrow();
+        assert_eq!(progression.len(), 3); // 3 retries before exhaustion
+        assert_eq!(progression[0], 0); // Before first sleep
+        assert_eq!(progression[1], 25); // After first sleep
+        assert_eq!(progression[2], 50); // After second sleep
     }
 
+    // ============================================================================
+    // RetryableExt Tests
+    // ============================================================================
+
     #[test]
-    fn test_retry_success_on_first_attempt() {
+    fn test_with_exponential_success() {
+        use super::RetryableExt;
+
         fn always_succeeds() -> Result<i32, TestError> {
             Ok(42)
         }
 
         let result = always_succeeds
-            .retry(ExponentialBackoff::default())
+            .with_exponential()
             .call_with_sleeper(FnSleeper(|_| {}));
 
         let outcome = result.expect("retry should succeed");
@@ -701,7 +3007,8 @@ mod tests {
     }
 
     #[test]
-    fn test_retry_success_after_failures() {
+    fn test_with_exponential_retry_behavior() {
+        use super::RetryableExt;
         use core::cell::Cell;
 
         let attempts = Cell::new(0);
@@ -713,75 +3020,225 @@ mod tests {
             if current < 2 {
                 Err(TestError::Retryable)
             } else {
-                Ok(42)
+                Ok(100)
             }
         };
 
         let result = operation
-            .retry(ExponentialBackoff::default().max_attempts(3))
+            .with_exponential()
             .call_with_sleeper(FnSleeper(|_| {}));
 
         let outcome = result.expect("retry should succeed");
         assert_eq!(outcome.attempts(), 3);
-        assert_eq!(outcome.into_inner(), 42);
+        assert_eq!(outcome.into_inner(), 100);
+        // Verify default max_attempts was used (3)
         assert_eq!(attempts.get(), 3);
     }
 
     #[test]
-    fn test_retry_exhausted() {
+    fn test_with_exponential_exhausted() {
+        use super::RetryableExt;
+
         fn always_fails() -> Result<i32, TestError> {
             Err(TestError::Retryable)
         }
 
         let result = always_fails
-            .retry(ExponentialBackoff::default().max_attempts(3))
+            .with_exponential()
             .call_with_sleeper(FnSleeper(|_| {}));
 
         let err = result.expect_err("retry should exhaust");
         assert_eq!(err.kind(), RetryErrorKind::Exhausted);
-        assert_eq!(err.attempts(), 3);
-        assert_eq!(err.max_attempts(), 3);
-        assert!(err.cumulative_delay_ms() > 0);
-        if let Some(cause) = err.cause() {
-            assert_eq!(cause, &TestError::Retryable);
-        } else {
-            panic!("expected underlying cause");
+        assert_eq!(err.attempts(), 3); // Default max_attempts
+        assert_eq!(err.max_attempts(), Some(3));
+    }
+
+    #[test]
+    fn test_with_exponential_chaining() {
+        use super::RetryableExt;
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 1 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(7)
+            }
+        };
+
+        // Test that .with_exponential() can be chained with other builder methods
+        let result = operation
+            .with_exponential()
+            .when(|e| matches!(e, TestError::Retryable))
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 2);
+        assert_eq!(outcome.into_inner(), 7);
+    }
+
+    #[test]
+    fn test_with_constant_success() {
+        use super::RetryableExt;
+
+        fn always_succeeds() -> Result<i32, TestError> {
+            Ok(99)
+        }
+
+        let result = always_succeeds
+            .with_constant(250)
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 1);
+        assert_eq!(outcome.into_inner(), 99);
+    }
+
+    #[test]
+    fn test_with_constant_uses_correct_delay() {
+        use super::RetryableExt;
+        use core::cell::{Cell, RefCell};
+
+        let attempts = Cell::new(0);
+        let delays = RefCell::new(Vec::new());
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 2 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(42)
+            }
+        };
+
+        let result = operation
+            .with_constant(500)
+            .notify(|ctx| {
+                if let Some(delay) = ctx.next_delay_ms {
+                    delays.borrow_mut().push(delay);
+                }
+            })
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 3);
+
+        // Verify constant delay was used (ConstantBackoff default has no jitter)
+        let recorded_delays = delays.borrow();
+        assert_eq!(recorded_delays.len(), 2); // Two retries
+        assert_eq!(recorded_delays[0], 500);
+        assert_eq!(recorded_delays[1], 500);
+    }
+
+    #[test]
+    fn test_with_constant_chaining() {
+        use super::RetryableExt;
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 1 {
+                Err(TestError::Fatal)
+            } else {
+                Ok(123)
+            }
+        };
+
+        // Test that .with_constant() can be chained with predicate
+        let result = operation
+            .with_constant(100)
+            .when(|e| matches!(e, TestError::Retryable))
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        // Should fail immediately due to predicate
+        let err = result.expect_err("retry should fail due to predicate");
+        assert_eq!(
+            err.kind(),
+            RetryErrorKind::PredicateRejected(Rejection::Predicate)
+        );
+        assert_eq!(err.attempts(), 1);
+    }
+
+    #[test]
+    fn test_with_fibonacci_success() {
+        use super::RetryableExt;
+
+        fn always_succeeds() -> Result<String, TestError> {
+            Ok("success".to_string())
         }
+
+        let result = always_succeeds
+            .with_fibonacci()
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 1);
+        assert_eq!(outcome.into_inner(), "success");
+    }
+
+    #[test]
+    fn test_with_fibonacci_retry_behavior() {
+        use super::RetryableExt;
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0);
+
+        let operation = || {
+            let current = attempts.get();
+            attempts.set(current + 1);
+
+            if current < 3 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(777)
+            }
+        };
+
+        let result = operation
+            .with_fibonacci()
+            .call_with_sleeper(FnSleeper(|_| {}));
+
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 4);
+        assert_eq!(outcome.into_inner(), 777);
     }
 
     #[test]
-    fn test_retry_when_predicate() {
-        fn fails_with_fatal() -> Result<i32, TestError> {
-            Err(TestError::Fatal)
+    fn test_with_fibonacci_exhausted() {
+        use super::RetryableExt;
+
+        fn always_fails() -> Result<i32, TestError> {
+            Err(TestError::Retryable)
         }
 
-        let result = fails_with_fatal
-            .retry(ExponentialBackoff::default())
-            .when(|e| matches!(e, TestError::Retryable))
+        let result = always_fails
+            .with_fibonacci()
             .call_with_sleeper(FnSleeper(|_| {}));
 
-        // Fatal error should not be retried
-        let err = result.expect_err("retry should stop due to predicate");
-        assert_eq!(err.kind(), RetryErrorKind::PredicateRejected);
-        if let Some(cause) = err.cause() {
-            assert_eq!(cause, &TestError::Fatal);
-        } else {
-            panic!("expected underlying cause");
-        }
+        let err = result.expect_err("retry should exhaust");
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 8); // Fibonacci default max_attempts
+        assert_eq!(err.max_attempts(), Some(8));
     }
 
     #[test]
-    fn test_retry_notify_callback() {
-        use core::cell::{Cell, RefCell};
-        #[cfg(feature = "std")]
-        use std::rc::Rc;
-
-        #[cfg(not(feature = "std"))]
-        use alloc::rc::Rc;
+    fn test_with_fibonacci_chaining() {
+        use super::RetryableExt;
+        use core::cell::Cell;
 
         let attempts = Cell::new(0);
-        let notify_calls = Rc::new(RefCell::new(Vec::new()));
-        let notify_calls_clone = Rc::clone(&notify_calls);
+        let success_count = Cell::new(0);
 
         let operation = || {
             let current = attempts.get();
@@ -790,139 +3247,167 @@ mod tests {
             if current < 2 {
                 Err(TestError::Retryable)
             } else {
-                Ok(42)
+                Ok(555)
             }
         };
 
+        // Test that .with_fibonacci() can be chained with callbacks
         let result = operation
-            .retry(ExponentialBackoff::default().max_attempts(3))
-            .notify(move |ctx| {
-                // Track notify calls
-                notify_calls_clone.borrow_mut().push((
-                    ctx.attempt,
-                    ctx.next_delay_ms,
-                    ctx.cumulative_delay_ms,
-                    ctx.error.is_some(),
-                ));
-                assert!(ctx.attempt >= 1);
-                assert!(ctx.next_delay_ms.is_some());
-                assert!(ctx.error.is_some());
+            .with_fibonacci()
+            .on_success(|_ctx| {
+                success_count.set(success_count.get() + 1);
             })
             .call_with_sleeper(FnSleeper(|_| {}));
 
         let outcome = result.expect("retry should succeed");
         assert_eq!(outcome.attempts(), 3);
-
-        // Verify notify was called twice (for the two failures)
-        let calls = notify_calls.borrow();
-        assert_eq!(calls.len(), 2);
-        assert_eq!(calls[0].0, 1); // First attempt
-        assert_eq!(calls[1].0, 2); // Second attempt
+        assert_eq!(outcome.into_inner(), 555);
+        assert_eq!(success_count.get(), 1);
     }
 
     #[test]
-    fn test_on_success_callback_invoked() {
+    fn test_all_extension_methods_produce_working_retries() {
+        use super::RetryableExt;
         use core::cell::Cell;
-        use core::sync::atomic::{AtomicUsize, Ordering};
-
-        static SUCCESS_ATTEMPT: AtomicUsize = AtomicUsize::new(0);
-        static SUCCESS_CUMULATIVE_DELAY: AtomicUsize = AtomicUsize::new(0);
 
-        let attempts = Cell::new(0);
+        let attempts_exp = Cell::new(0);
+        let op_exp = || {
+            let current = attempts_exp.get();
+            attempts_exp.set(current + 1);
+            if current < 1 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(1)
+            }
+        };
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        let attempts_const = Cell::new(0);
+        let op_const = || {
+            let current = attempts_const.get();
+            attempts_const.set(current + 1);
+            if current < 1 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(2)
+            }
+        };
 
+        let attempts_fib = Cell::new(0);
+        let op_fib = || {
+            let current = attempts_fib.get();
+            attempts_fib.set(current + 1);
             if current < 1 {
                 Err(TestError::Retryable)
             } else {
-                Ok(7)
+                Ok(3)
             }
         };
 
-        SUCCESS_ATTEMPT.store(0, Ordering::SeqCst);
-        SUCCESS_CUMULATIVE_DELAY.store(0, Ordering::SeqCst);
+        // All extension methods should produce working retry builders
+        let r1 = op_exp
+            .with_exponential()
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect("exponential retry works");
+
+        let r2 = op_const
+            .with_constant(100)
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect("constant retry works");
 
-        let outcome = operation
-            .retry(ExponentialBackoff::default().max_attempts(3))
-            .on_success(|ctx| {
-                SUCCESS_ATTEMPT.store(ctx.attempt as usize, Ordering::SeqCst);
-                SUCCESS_CUMULATIVE_DELAY.store(ctx.cumulative_delay_ms as usize, Ordering::SeqCst);
-                assert!(ctx.error.is_none());
-                assert!(ctx.next_delay_ms.is_none());
-            })
+        let r3 = op_fib
+            .with_fibonacci()
             .call_with_sleeper(FnSleeper(|_| {}))
-            .expect("retry should succeed");
+            .expect("fibonacci retry works");
 
-        assert_eq!(outcome.into_inner(), 7);
-        assert_eq!(SUCCESS_ATTEMPT.load(Ordering::SeqCst), 2);
-        // Should have some cumulative delay from the first retry
-        assert!(SUCCESS_CUMULATIVE_DELAY.load(Ordering::SeqCst) > 0);
+        assert_eq!(r1.into_inner(), 1);
+        assert_eq!(r2.into_inner(), 2);
+        assert_eq!(r3.into_inner(), 3);
     }
 
     #[test]
-    fn test_on_failure_callback_invoked() {
-        use core::sync::atomic::{AtomicUsize, Ordering};
-
-        static FAILURE_KIND: AtomicUsize = AtomicUsize::new(0);
-        static FAILURE_CUMULATIVE_DELAY: AtomicUsize = AtomicUsize::new(0);
+    fn test_with_linear_and_polynomial() {
+        use super::RetryableExt;
+        use core::cell::Cell;
 
-        fn always_fails() -> Result<(), TestError> {
-            Err(TestError::Retryable)
-        }
+        let attempts_linear = Cell::new(0);
+        let op_linear = || {
+            let current = attempts_linear.get();
+            attempts_linear.set(current + 1);
+            if current < 2 {
+                Err(TestError::Retryable)
+            } else {
+                Ok("linear")
+            }
+        };
 
-        FAILURE_KIND.store(0, Ordering::SeqCst);
-        FAILURE_CUMULATIVE_DELAY.store(0, Ordering::SeqCst);
+        let attempts_poly = Cell::new(0);
+        let op_poly = || {
+            let current = attempts_poly.get();
+            attempts_poly.set(current + 1);
+            Err::<(), _>(TestError::Retryable)
+        };
 
-        let result = always_fails
-            .retry(ExponentialBackoff::default().max_attempts(2))
-            .on_failure(|err| {
-                let marker = match err.kind() {
-                    RetryErrorKind::Exhausted => 1,
-                    RetryErrorKind::PredicateRejected => 2,
-                };
-                FAILURE_KIND.store(marker, Ordering::SeqCst);
-                FAILURE_CUMULATIVE_DELAY.store(err.cumulative_delay_ms() as usize, Ordering::SeqCst);
-            })
-            .call_with_sleeper(FnSleeper(|_| {}));
+        let outcome = op_linear
+            .with_linear()
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect("linear retry works");
+        assert_eq!(outcome.attempts(), 3);
+        assert_eq!(outcome.into_inner(), "linear");
 
-        assert!(result.is_err());
-        assert_eq!(FAILURE_KIND.load(Ordering::SeqCst), 1);
-        // Should have cumulative delay from retry attempt
-        assert!(FAILURE_CUMULATIVE_DELAY.load(Ordering::SeqCst) > 0);
+        let err = op_poly
+            .with_polynomial()
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("polynomial retry should exhaust");
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3); // Default max_attempts
+        assert_eq!(attempts_poly.get(), 3);
     }
 
     #[test]
-    fn test_constant_backoff_retry() {
-        use core::cell::Cell;
+    fn test_with_decorrelated_threads_previous_delay() {
+        use super::RetryableExt;
+        use core::cell::RefCell;
 
-        let attempts = Cell::new(0);
+        let delays = RefCell::new(Vec::new());
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        fn always_fails() -> Result<(), TestError> {
+            Err(TestError::Retryable)
+        }
 
-            if current < 1 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(42)
-            }
-        };
+        let err = always_fails
+            .retry(
+                crate::backoff::DecorrelatedJitterBackoff::new()
+                    .base_delay_ms(10)
+                    .max_delay_ms(100_000)
+                    .max_attempts(6),
+            )
+            .notify(|ctx| {
+                delays.borrow_mut().push(ctx.next_delay_ms.unwrap());
+            })
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
 
-        let result = operation
-            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(2))
-            .call_with_sleeper(FnSleeper(|_| {}));
+        assert_eq!(err.attempts(), 6);
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 2);
-        assert_eq!(outcome.into_inner(), 42);
-        assert_eq!(attempts.get(), 2);
+        let delays = delays.borrow();
+        assert_eq!(delays.len(), 5);
+        assert!(delays[0] >= 10 && delays[0] <= 30);
+        // Reported delays are truncated to whole milliseconds
+        for pair in delays.windows(2) {
+            assert!(pair[1] >= 10 && pair[1] <= (pair[0] + 1) * 3);
+        }
+
+        let outcome = (|| Ok::<_, TestError>(1))
+            .with_decorrelated()
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect("retry should succeed");
+        assert_eq!(outcome.attempts(), 1);
     }
 
     #[cfg(feature = "std")]
     #[test]
-    fn test_retry_with_std_sleeper() {
+    fn test_with_exponential_std_sleeper() {
+        use super::RetryableExt;
         use core::cell::Cell;
 
         let attempts = Cell::new(0);
@@ -934,578 +3419,1291 @@ mod tests {
             if current < 1 {
                 Err(TestError::Retryable)
             } else {
-                Ok(42)
+                Ok(999)
             }
         };
 
-        let start = std::time::Instant::now();
-        let result = operation
-            .retry(
-                ConstantBackoff::new()
-                    .delay_ms(10)
-                    .max_attempts(2)
-                    .jitter_factor(0.0),
-            )
-            .call();
-
-        let elapsed = start.elapsed();
+        // Test with std sleeper
+        let result = operation.with_exponential().call();
 
         let outcome = result.expect("retry should succeed");
         assert_eq!(outcome.attempts(), 2);
-        assert_eq!(outcome.into_inner(), 42);
-        assert!(elapsed.as_millis() >= 9); // At least one 10ms sleep
+        assert_eq!(outcome.into_inner(), 999);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_retry_context_comprehensive() {
-        use core::cell::{Cell, RefCell};
-        #[cfg(feature = "std")]
-        use std::rc::Rc;
-
-        #[cfg(not(feature = "std"))]
-        use alloc::rc::Rc;
-
-        let attempts = Cell::new(0);
-        let notify_contexts = Rc::new(RefCell::new(Vec::new()));
-        let notify_contexts_clone = Rc::clone(&notify_contexts);
-
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
-
-            if current < 3 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(42)
-            }
-        };
+    fn test_with_constant_std_sleeper() {
+        use super::RetryableExt;
 
-        let result = operation
-            .retry(ConstantBackoff::new().delay_ms(100).max_attempts(5).jitter_factor(0.0))
-            .notify(move |ctx| {
-                // Capture context for verification
-                notify_contexts_clone.borrow_mut().push((
-                    ctx.attempt,
-                    ctx.next_delay_ms,
-                    ctx.cumulative_delay_ms,
-                ));
+        fn always_succeeds() -> Result<i32, TestError> {
+            Ok(888)
+        }
 
-                // Verify error is present during notify
-                assert!(ctx.error.is_some());
-                if let Some(err) = ctx.error {
-                    assert_eq!(err, &TestError::Retryable);
-                }
-            })
-            .call_with_sleeper(FnSleeper(|_| {}));
+        // Test with std sleeper
+        let result = always_succeeds.with_constant(50).call();
 
         let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 4);
+        assert_eq!(outcome.into_inner(), 888);
+    }
 
-        // Verify notify contexts
-        let contexts = notify_contexts.borrow();
-        assert_eq!(contexts.len(), 3); // Three failures before success
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_fibonacci_std_sleeper() {
+        use super::RetryableExt;
 
-        // First failure
-        assert_eq!(contexts[0].0, 1);
-        assert_eq!(contexts[0].1, Some(100));
-        assert_eq!(contexts[0].2, 0); // No cumulative delay yet
+        fn always_succeeds() -> Result<i32, TestError> {
+            Ok(444)
+        }
 
-        // Second failure
-        assert_eq!(contexts[1].0, 2);
-        assert_eq!(contexts[1].1, Some(100));
-        assert_eq!(contexts[1].2, 100); // One delay accumulated
+        // Test with std sleeper
+        let result = always_succeeds.with_fibonacci().call();
 
-        // Third failure
-        assert_eq!(contexts[2].0, 3);
-        assert_eq!(contexts[2].1, Some(100));
-        assert_eq!(contexts[2].2, 200); // Two delays accumulated
+        let outcome = result.expect("retry should succeed");
+        assert_eq!(outcome.into_inner(), 444);
     }
 
     #[test]
-    fn test_retry_context_on_success() {
-        use core::cell::{Cell, RefCell};
-        #[cfg(feature = "std")]
-        use std::rc::Rc;
+    fn test_sub_millisecond_delays_reach_sleeper() {
+        use core::cell::RefCell;
+        use core::time::Duration;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
 
-        #[cfg(not(feature = "std"))]
-        use alloc::rc::Rc;
+        struct RecordSleeps<'a>(&'a RefCell<Vec<Duration>>);
 
-        let attempts = Cell::new(0);
-        let success_context = Rc::new(RefCell::new(None));
-        let success_context_clone = Rc::clone(&success_context);
+        impl Sleeper for RecordSleeps<'_> {
+            fn sleep_ms(&self, ms: u64) {
+                self.sleep(Duration::from_millis(ms));
+            }
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+            fn sleep(&self, duration: Duration) {
+                self.0.borrow_mut().push(duration);
+            }
+        }
 
-            if current < 2 {
+        let sleeps = RefCell::new(Vec::new());
+        let mut calls = 0;
+        let outcome = (|| {
+            calls += 1;
+            if calls < 3 {
                 Err(TestError::Retryable)
             } else {
-                Ok(100)
+                Ok(calls)
             }
-        };
+        })
+        .retry(
+            ExponentialBackoff::new()
+                .base_delay(Duration::from_micros(100))
+                .max_attempts(5)
+                .jitter_factor(0.0),
+        )
+        .call_with_sleeper_and_rng(RecordSleeps(&sleeps), StdRng::seed_from_u64(42))
+        .expect("third attempt succeeds");
+
+        assert_eq!(
+            *sleeps.borrow(),
+            vec![Duration::from_micros(100), Duration::from_micros(200)]
+        );
+        assert_eq!(outcome.cumulative_delay(), Duration::from_micros(300));
+        assert_eq!(outcome.cumulative_delay_ms(), 0);
+    }
 
-        let result = operation
-            .retry(ConstantBackoff::new().delay_ms(50).max_attempts(5).jitter_factor(0.0))
-            .on_success(move |ctx| {
-                success_context_clone.borrow_mut().replace((
-                    ctx.attempt,
-                    ctx.next_delay_ms,
-                    ctx.cumulative_delay_ms,
-                    ctx.error.is_none(),
-                ));
-            })
-            .call_with_sleeper(FnSleeper(|_| {}));
+    #[test]
+    fn test_unlimited_retries_past_255_attempts() {
+        let mut calls = 0u32;
+        let outcome = (|| {
+            calls += 1;
+            if calls <= 300 {
+                Err(TestError::Retryable)
+            } else {
+                Ok(calls)
+            }
+        })
+        .retry(ConstantBackoff::new().delay_ms(0).unlimited())
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("retries until success");
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 3);
-        assert_eq!(outcome.cumulative_delay_ms(), 100); // 2 delays of 50ms
+        assert_eq!(outcome.attempts(), 301);
+        assert_eq!(*outcome.value(), 301);
+    }
 
-        // Verify success context
-        let ctx = success_context.borrow();
-        assert!(ctx.is_some());
-        let (attempt, next_delay, cumulative, no_error) = ctx.unwrap();
-        assert_eq!(attempt, 3);
-        assert_eq!(next_delay, None); // No next delay on success
-        assert_eq!(cumulative, 100);
-        assert!(no_error); // Error should be None
+    #[test]
+    fn test_attempt_counts_beyond_u8() {
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(ConstantBackoff::new().delay_ms(0).max_attempts(1_000))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
+
+        assert_eq!(err.attempts(), 1_000);
+        assert_eq!(err.max_attempts(), Some(1_000));
+    }
+
+    /// Test clock that only advances when the retry loop sleeps.
+    #[derive(Clone, Default)]
+    struct SimulatedTime(alloc::rc::Rc<core::cell::Cell<Duration>>);
+
+    impl crate::clock::Clock for SimulatedTime {
+        fn now(&self) -> Duration {
+            self.0.get()
+        }
+    }
+
+    impl Sleeper for SimulatedTime {
+        fn sleep_ms(&self, ms: u64) {
+            self.sleep(Duration::from_millis(ms));
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
     }
 
     #[test]
-    fn test_retry_context_cumulative_accuracy() {
-        use core::cell::{Cell, RefCell};
-        #[cfg(feature = "std")]
-        use std::rc::Rc;
+    fn test_max_elapsed_clips_final_sleep() {
+        let time = SimulatedTime::default();
+        let delays = core::cell::RefCell::new(Vec::new());
+
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(ConstantBackoff::new().delay_ms(100).unlimited())
+            .max_elapsed(Duration::from_millis(250))
+            .clock(time.clone())
+            .notify(|ctx| delays.borrow_mut().push(ctx.next_delay_ms.unwrap()))
+            .call_with_sleeper(time.clone())
+            .expect_err("deadline should stop retries");
+
+        assert_eq!(err.kind(), RetryErrorKind::DeadlineExceeded);
+        assert_eq!(err.attempts(), 4);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(250));
+        assert_eq!(*delays.borrow(), vec![100, 100, 50]);
+        assert_eq!(time.now(), Duration::from_millis(250));
+    }
 
-        #[cfg(not(feature = "std"))]
-        use alloc::rc::Rc;
+    #[test]
+    fn test_max_elapsed_from_strategy_and_builder_override() {
+        let time = SimulatedTime::default();
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(
+                ConstantBackoff::new()
+                    .delay_ms(100)
+                    .unlimited()
+                    .max_elapsed(Duration::from_millis(200)),
+            )
+            .clock(time.clone())
+            .call_with_sleeper(time.clone())
+            .expect_err("strategy budget should stop retries");
 
-        let attempts = Cell::new(0);
-        let cumulative_progression = Rc::new(RefCell::new(Vec::new()));
-        let cumulative_progression_clone = Rc::clone(&cumulative_progression);
+        assert_eq!(err.kind(), RetryErrorKind::DeadlineExceeded);
+        assert_eq!(err.attempts(), 3);
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
-            Err::<(), TestError>(TestError::Retryable)
-        };
+        let time = SimulatedTime::default();
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(
+                ConstantBackoff::new()
+                    .delay_ms(100)
+                    .unlimited()
+                    .max_elapsed(Duration::from_millis(200)),
+            )
+            .max_elapsed(Duration::from_millis(500))
+            .clock(time.clone())
+            .call_with_sleeper(time.clone())
+            .expect_err("builder budget should stop retries");
 
-        let _result = operation
-            .retry(ConstantBackoff::new().delay_ms(25).max_attempts(4).jitter_factor(0.0))
-            .notify(move |ctx| {
-                cumulative_progression_clone.borrow_mut().push(ctx.cumulative_delay_ms);
-            })
-            .call_with_sleeper(FnSleeper(|_| {}));
+        assert_eq!(err.attempts(), 6);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(500));
+    }
 
-        // Verify cumulative delay progression
-        let progression = cumulative_progression.borrow();
-        assert_eq!(progression.len(), 3); // 3 retries before exhaustion
-        assert_eq!(progression[0], 0);    // Before first sleep
-        assert_eq!(progression[1], 25);   // After first sleep
-        assert_eq!(progression[2], 50);   // After second sleep
+    #[test]
+    fn test_attempt_limit_wins_within_budget() {
+        let time = SimulatedTime::default();
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .max_elapsed(Duration::from_secs(60))
+            .clock(time.clone())
+            .call_with_sleeper(time)
+            .expect_err("retry should exhaust");
+
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3);
     }
 
-    // ============================================================================
-    // RetryableExt Tests
-    // ============================================================================
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_past_deadline_fails_without_sleeping() {
+        let err = (|| Err::<(), _>("unavailable"))
+            .retry(ConstantBackoff::new().delay_ms(1_000).unlimited())
+            .deadline(std::time::Instant::now())
+            .call()
+            .expect_err("deadline already passed");
+
+        assert_eq!(err.kind(), RetryErrorKind::DeadlineExceeded);
+        assert_eq!(err.attempts(), 1);
+        assert_eq!(err.cumulative_delay(), Duration::ZERO);
+        assert_eq!(
+            err.to_string(),
+            "retry deadline exceeded after 1 attempts (cumulative delay 0ns): unavailable"
+        );
+    }
 
     #[test]
-    fn test_with_exponential_success() {
-        use super::RetryableExt;
+    fn test_stop_when_replaces_strategy_limit() {
+        use crate::jitter::Jitter;
+        use crate::stop;
 
-        fn always_succeeds() -> Result<i32, TestError> {
-            Ok(42)
-        }
+        let delays = core::cell::RefCell::new(Vec::new());
 
-        let result = always_succeeds
-            .with_exponential()
-            .call_with_sleeper(FnSleeper(|_| {}));
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(
+                ExponentialBackoff::new()
+                    .base_delay_ms(10)
+                    .jitter(Jitter::None)
+                    .max_attempts(3),
+            )
+            .stop_when(stop::after_attempts(6))
+            .notify(|ctx| delays.borrow_mut().push(ctx.next_delay_ms.unwrap()))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 1);
-        assert_eq!(outcome.into_inner(), 42);
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 6);
+        assert_eq!(err.max_attempts(), Some(6));
+        // Past the strategy's limit the last delay is repeated
+        assert_eq!(*delays.borrow(), vec![10, 20, 20, 20, 20]);
     }
 
     #[test]
-    fn test_with_exponential_retry_behavior() {
-        use super::RetryableExt;
-        use core::cell::Cell;
+    fn test_stop_when_with_single_attempt_strategy() {
+        use crate::stop;
+
+        let time = SimulatedTime::default();
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(ConstantBackoff::new().delay_ms(100).max_attempts(1))
+            .stop_when(stop::after_elapsed(Duration::from_millis(250)))
+            .clock(time.clone())
+            .call_with_sleeper(time.clone())
+            .expect_err("retry should stop");
+
+        // The strategy's own limit of one attempt doesn't end the run
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 4);
+        assert_eq!(time.now(), Duration::from_millis(300));
+    }
 
-        let attempts = Cell::new(0);
+    #[test]
+    fn test_stop_when_time_conditions() {
+        use crate::stop::{self, StopConditionExt};
+
+        let time = SimulatedTime::default();
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(ConstantBackoff::new().delay_ms(100).max_attempts(2))
+            .stop_when(stop::after_delay(Duration::from_millis(300)))
+            .clock(time.clone())
+            .call_with_sleeper(time.clone())
+            .expect_err("retry should stop");
+
+        assert_eq!(err.attempts(), 4);
+        assert_eq!(err.max_attempts(), None);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(300));
+
+        // Elapsed stop conditions do not clip the final sleep
+        let time = SimulatedTime::default();
+        let err = (|| Err::<(), _>(TestError::Retryable))
+            .retry(ConstantBackoff::new().delay_ms(100))
+            .stop_when(stop::after_elapsed(Duration::from_millis(250)).or(stop::after_attempts(50)))
+            .clock(time.clone())
+            .call_with_sleeper(time.clone())
+            .expect_err("retry should stop");
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 4);
+        assert_eq!(err.max_attempts(), Some(50));
+        assert_eq!(time.now(), Duration::from_millis(300));
+    }
 
-            if current < 2 {
+    #[test]
+    fn test_stop_never_retries_until_success() {
+        use core::cell::Cell;
+
+        let attempts = Cell::new(0u32);
+        let outcome = (|| {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() < 20 {
                 Err(TestError::Retryable)
             } else {
-                Ok(100)
+                Ok(attempts.get())
             }
-        };
+        })
+        .retry(ConstantBackoff::new().delay_ms(1).max_attempts(2))
+        .stop_when(crate::stop::never())
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("retry should eventually succeed");
+
+        assert_eq!(outcome.attempts(), 20);
+        assert_eq!(outcome.into_inner(), 20);
+    }
 
-        let result = operation
-            .with_exponential()
-            .call_with_sleeper(FnSleeper(|_| {}));
+    #[derive(Debug)]
+    enum UpstreamError {
+        RateLimited(Duration),
+        Unauthorized,
+        Timeout,
+    }
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 3);
-        assert_eq!(outcome.into_inner(), 100);
-        // Verify default max_attempts was used (3)
-        assert_eq!(attempts.get(), 3);
+    impl RetryHint for UpstreamError {
+        fn retry_hint(&self) -> Option<RetryAdvice> {
+            match self {
+                UpstreamError::RateLimited(delay) => Some(RetryAdvice::RetryAfter(*delay)),
+                UpstreamError::Unauthorized => Some(RetryAdvice::DoNotRetry),
+                UpstreamError::Timeout => None,
+            }
+        }
     }
 
     #[test]
-    fn test_with_exponential_exhausted() {
-        use super::RetryableExt;
+    fn test_retry_hints_override_strategy_delay() {
+        use core::cell::{Cell, RefCell};
 
-        fn always_fails() -> Result<i32, TestError> {
-            Err(TestError::Retryable)
-        }
+        let attempts = Cell::new(0u32);
+        let delays = RefCell::new(Vec::new());
 
-        let result = always_fails
-            .with_exponential()
-            .call_with_sleeper(FnSleeper(|_| {}));
+        let outcome = (|| {
+            attempts.set(attempts.get() + 1);
+            match attempts.get() {
+                1 => Err(UpstreamError::RateLimited(Duration::from_millis(750))),
+                2 => Err(UpstreamError::RateLimited(Duration::from_secs(3_600))),
+                3 => Err(UpstreamError::Timeout),
+                _ => Ok("done"),
+            }
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .respect_retry_hints(Duration::from_secs(5))
+        .notify(|ctx| {
+            delays
+                .borrow_mut()
+                .push((ctx.next_delay.unwrap(), ctx.delay_source.unwrap()));
+        })
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("retry should succeed");
 
-        let err = result.expect_err("retry should exhaust");
-        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
-        assert_eq!(err.attempts(), 3); // Default max_attempts
-        assert_eq!(err.max_attempts(), 3);
+        assert_eq!(outcome.attempts(), 4);
+        assert_eq!(
+            *delays.borrow(),
+            vec![
+                (Duration::from_millis(750), DelaySource::Hint),
+                (Duration::from_secs(5), DelaySource::Hint),
+                (Duration::from_millis(10), DelaySource::Strategy),
+            ]
+        );
+        assert_eq!(outcome.cumulative_delay(), Duration::from_millis(5_760));
     }
 
     #[test]
-    fn test_with_exponential_chaining() {
-        use super::RetryableExt;
-        use core::cell::Cell;
+    fn test_suggested_delays_need_no_strategy_delay() {
+        use crate::backoff::FixedScheduleBackoff;
+        use crate::stop;
 
-        let attempts = Cell::new(0);
+        // An empty schedule never produces a delay of its own
+        let backoff = FixedScheduleBackoff::new([]).unlimited();
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        let err = (|| Err::<(), _>(UpstreamError::RateLimited(Duration::from_millis(5))))
+            .retry(backoff)
+            .respect_retry_hints(Duration::from_secs(1))
+            .stop_when(stop::after_attempts(3))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(10));
 
-            if current < 1 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(7)
-            }
-        };
+        let err = (|| Err::<(), _>(DbError::Throttled))
+            .retry(backoff)
+            .classify(classify_db_error)
+            .stop_when(stop::after_attempts(3))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(500));
+    }
 
-        // Test that .with_exponential() can be chained with other builder methods
-        let result = operation
-            .with_exponential()
-            .when(|e| matches!(e, TestError::Retryable))
-            .call_with_sleeper(FnSleeper(|_| {}));
+    #[test]
+    fn test_retry_hint_do_not_retry() {
+        let err = (|| Err::<(), _>(UpstreamError::Unauthorized))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+            .respect_retry_hints(Duration::from_secs(5))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("hint should stop retries");
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 2);
-        assert_eq!(outcome.into_inner(), 7);
+        assert_eq!(
+            err.kind(),
+            RetryErrorKind::PredicateRejected(Rejection::Hint)
+        );
+        assert_eq!(err.attempts(), 1);
     }
 
     #[test]
-    fn test_with_constant_success() {
-        use super::RetryableExt;
+    fn test_retry_hints_respect_attempt_limit() {
+        let err = (|| Err::<(), _>(UpstreamError::RateLimited(Duration::from_millis(1))))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .respect_retry_hints(Duration::from_secs(5))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
 
-        fn always_succeeds() -> Result<i32, TestError> {
-            Ok(99)
-        }
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(2));
+    }
 
-        let result = always_succeeds
-            .with_constant(250)
-            .call_with_sleeper(FnSleeper(|_| {}));
+    #[derive(Debug)]
+    enum DbError {
+        Deadlock,
+        PoolExhausted,
+        Throttled,
+        ConstraintViolation,
+    }
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 1);
-        assert_eq!(outcome.into_inner(), 99);
+    fn classify_db_error(error: &DbError) -> Classification {
+        match error {
+            DbError::Deadlock => Classification::Retry,
+            DbError::PoolExhausted => Classification::RetryWithPolicy(
+                ConstantBackoff::new().delay_ms(7).jitter_factor(0.0).into(),
+            ),
+            DbError::Throttled => Classification::RetryAfter(Duration::from_millis(250)),
+            DbError::ConstraintViolation => Classification::Fail,
+        }
     }
 
     #[test]
-    fn test_with_constant_uses_correct_delay() {
-        use super::RetryableExt;
+    fn test_classify_routes_delays() {
         use core::cell::{Cell, RefCell};
-        #[cfg(feature = "std")]
-        use std::rc::Rc;
 
-        #[cfg(not(feature = "std"))]
-        use alloc::rc::Rc;
+        let attempts = Cell::new(0u32);
+        let delays = RefCell::new(Vec::new());
 
-        let attempts = Cell::new(0);
-        let delays = Rc::new(RefCell::new(Vec::new()));
-        let delays_clone = Rc::clone(&delays);
+        let outcome = (|| {
+            attempts.set(attempts.get() + 1);
+            match attempts.get() {
+                1 => Err(DbError::Deadlock),
+                2 => Err(DbError::PoolExhausted),
+                3 => Err(DbError::Throttled),
+                _ => Ok(()),
+            }
+        })
+        .retry(
+            ConstantBackoff::new()
+                .delay_ms(100)
+                .jitter_factor(0.0)
+                .max_attempts(5),
+        )
+        .classify(classify_db_error)
+        .notify(|ctx| {
+            delays
+                .borrow_mut()
+                .push((ctx.next_delay_ms.unwrap(), ctx.delay_source.unwrap()));
+        })
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("retry should succeed");
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        assert_eq!(outcome.attempts(), 4);
+        assert_eq!(
+            *delays.borrow(),
+            vec![
+                (100, DelaySource::Strategy),
+                (7, DelaySource::Classifier),
+                (250, DelaySource::Classifier),
+            ]
+        );
+    }
 
-            if current < 2 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(42)
+    #[test]
+    fn test_classify_fail_reports_rejection() {
+        let err = (|| Err::<(), _>(DbError::ConstraintViolation))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+            .classify(classify_db_error)
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("classifier should stop retries");
+
+        assert_eq!(
+            err.kind(),
+            RetryErrorKind::PredicateRejected(Rejection::Classifier)
+        );
+        assert_eq!(err.attempts(), 1);
+    }
+
+    #[test]
+    fn test_classify_takes_precedence_over_hints() {
+        struct AlwaysFast;
+
+        impl Classify<UpstreamError> for AlwaysFast {
+            fn classify(&self, _error: &UpstreamError) -> Classification {
+                Classification::RetryAfter(Duration::from_millis(1))
             }
-        };
+        }
 
-        let result = operation
-            .with_constant(500)
-            .notify(move |ctx| {
-                if let Some(delay) = ctx.next_delay_ms {
-                    delays_clone.borrow_mut().push(delay);
-                }
-            })
-            .call_with_sleeper(FnSleeper(|_| {}));
+        let err = (|| Err::<(), _>(UpstreamError::Unauthorized))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .classify(AlwaysFast)
+            .respect_retry_hints(Duration::from_secs(5))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("retry should exhaust");
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 3);
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(2));
+    }
 
-        // Verify constant delay was used (ConstantBackoff default has no jitter)
-        let recorded_delays = delays.borrow();
-        assert_eq!(recorded_delays.len(), 2); // Two retries
-        assert_eq!(recorded_delays[0], 500);
-        assert_eq!(recorded_delays[1], 500);
+    #[test]
+    fn test_rejection_display() {
+        let err = (|| Err::<(), _>("forbidden"))
+            .retry(ConstantBackoff::new().delay_ms(10))
+            .classify(|_: &&str| Classification::Fail)
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("classifier should stop retries");
+
+        assert_eq!(
+            err.to_string(),
+            "retry aborted by classifier on attempt 1 (cumulative delay 0ns): forbidden"
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum JobStatus {
+        Pending,
+        Done(u32),
+    }
+
+    #[test]
+    fn test_retry_if_ok_retries_unacceptable_values() {
+        use core::cell::{Cell, RefCell};
+
+        let polls = Cell::new(0u32);
+        let contexts = RefCell::new(Vec::new());
+
+        let outcome = (|| {
+            polls.set(polls.get() + 1);
+            if polls.get() < 3 {
+                Ok::<_, &str>(JobStatus::Pending)
+            } else {
+                Ok(JobStatus::Done(7))
+            }
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        // Errors are never retried, rejected values still are
+        .when(|_| false)
+        .retry_if_ok(|status| *status == JobStatus::Pending)
+        .notify(|ctx| {
+            contexts
+                .borrow_mut()
+                .push((ctx.attempt, ctx.error.is_some()));
+        })
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("job should finish");
+
+        assert_eq!(outcome.attempts(), 3);
+        assert_eq!(outcome.into_inner(), JobStatus::Done(7));
+        assert_eq!(*contexts.borrow(), vec![(1, false), (2, false)]);
     }
 
     #[test]
-    fn test_with_constant_chaining() {
-        use super::RetryableExt;
-        use core::cell::Cell;
+    fn test_retry_if_ok_exhausted_keeps_last_value() {
+        let err = (|| Ok::<_, &str>(JobStatus::Pending))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .retry_if_ok(|status| *status == JobStatus::Pending)
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("job never finishes");
 
-        let attempts = Cell::new(0);
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3);
+        assert!(err.cause().is_none());
+        assert_eq!(err.last_value(), Some(&JobStatus::Pending));
+        assert_eq!(
+            err.to_string(),
+            "retry exhausted after 3 of 3 attempts (cumulative delay 20ms): \
+             result rejected by retry_if_ok"
+        );
+        assert_eq!(err.into_last_value(), Some(JobStatus::Pending));
+    }
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+    #[test]
+    fn test_retry_if_ok_holds_value_and_error() {
+        use core::cell::Cell;
 
-            if current < 1 {
-                Err(TestError::Fatal)
-            } else {
-                Ok(123)
+        let polls = Cell::new(0u32);
+        let err = (|| {
+            polls.set(polls.get() + 1);
+            match polls.get() {
+                1 => Ok(JobStatus::Pending),
+                _ => Err("timeout"),
             }
-        };
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+        .retry_if_ok(|status| *status == JobStatus::Pending)
+        .on_failure(|err| assert_eq!(err.cause(), Some(&"timeout")))
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect_err("retry should exhaust");
+
+        assert_eq!(err.cause(), Some(&"timeout"));
+        assert_eq!(err.last_value(), Some(&JobStatus::Pending));
+    }
 
-        // Test that .with_constant() can be chained with predicate
-        let result = operation
-            .with_constant(100)
-            .when(|e| matches!(e, TestError::Retryable))
-            .call_with_sleeper(FnSleeper(|_| {}));
+    #[test]
+    fn test_fallback_replaces_exhausted_result() {
+        use core::cell::{Cell, RefCell};
 
-        // Should fail immediately due to predicate
-        let err = result.expect_err("retry should fail due to predicate");
-        assert_eq!(err.kind(), RetryErrorKind::PredicateRejected);
-        assert_eq!(err.attempts(), 1);
+        let events = RefCell::new(Vec::new());
+        let attempts = Cell::new(0u32);
+
+        let outcome = (|| {
+            attempts.set(attempts.get() + 1);
+            Err::<&str, _>(TestError::Retryable)
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+        .on_failure(|_| events.borrow_mut().push("on_failure"))
+        .fallback(|err| {
+            events.borrow_mut().push("fallback");
+            assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+            assert_eq!(err.cause(), Some(&TestError::Retryable));
+            Ok("cached")
+        })
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("fallback should supply a value");
+
+        assert!(outcome.used_fallback());
+        assert_eq!(outcome.attempts(), 3);
+        assert_eq!(outcome.cumulative_delay(), Duration::from_millis(20));
+        assert_eq!(outcome.into_inner(), "cached");
+        assert_eq!(attempts.get(), 3);
+        assert_eq!(*events.borrow(), vec!["on_failure", "fallback"]);
     }
 
     #[test]
-    fn test_with_fibonacci_success() {
-        use super::RetryableExt;
+    fn test_fallback_on_rejection_and_success() {
+        let outcome = (|| Err::<u32, _>(TestError::Fatal))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .when(|e| matches!(e, TestError::Retryable))
+            .fallback(|err| {
+                assert_eq!(
+                    err.kind(),
+                    RetryErrorKind::PredicateRejected(Rejection::Predicate)
+                );
+                Ok(0)
+            })
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect("fallback should supply a value");
+        assert!(outcome.used_fallback());
+        assert_eq!(outcome.attempts(), 1);
 
-        fn always_succeeds() -> Result<String, TestError> {
-            Ok("success".to_string())
-        }
+        let outcome = (|| Ok::<u32, TestError>(7))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .fallback(|_| panic!("fallback must not run on success"))
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect("operation succeeds");
+        assert!(!outcome.used_fallback());
+        assert_eq!(outcome.into_inner(), 7);
+    }
 
-        let result = always_succeeds
-            .with_fibonacci()
-            .call_with_sleeper(FnSleeper(|_| {}));
+    #[test]
+    fn test_failed_fallback_keeps_original_error() {
+        let err = (|| Ok::<_, TestError>(JobStatus::Pending))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(2))
+            .fallback(|_| Err(TestError::Fatal))
+            .retry_if_ok(|status| *status == JobStatus::Pending)
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("fallback fails too");
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 1);
-        assert_eq!(outcome.into_inner(), "success");
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.cause(), None);
+        assert_eq!(err.last_value(), Some(&JobStatus::Pending));
     }
 
     #[test]
-    fn test_with_fibonacci_retry_behavior() {
-        use super::RetryableExt;
+    fn test_collect_errors_history_on_failure() {
         use core::cell::Cell;
 
-        let attempts = Cell::new(0);
+        let time = SimulatedTime::default();
+        let attempts = Cell::new(0u32);
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        let err = (|| {
+            attempts.set(attempts.get() + 1);
+            Err::<(), _>(match attempts.get() {
+                2 => TestError::Fatal,
+                _ => TestError::Retryable,
+            })
+        })
+        .retry(ConstantBackoff::new().delay_ms(100).max_attempts(3))
+        .collect_errors()
+        .clock(time.clone())
+        .call_with_sleeper(time.clone())
+        .expect_err("retry should exhaust");
+
+        assert_eq!(
+            err.history(),
+            &[
+                AttemptRecord {
+                    attempt: 1,
+                    error: TestError::Retryable,
+                    delay: Duration::from_millis(100),
+                    timestamp: Duration::ZERO,
+                },
+                AttemptRecord {
+                    attempt: 2,
+                    error: TestError::Fatal,
+                    delay: Duration::from_millis(100),
+                    timestamp: Duration::from_millis(100),
+                },
+            ]
+        );
+        assert_eq!(err.cause(), Some(&TestError::Retryable));
+        assert_eq!(err.into_history().len(), 2);
+    }
 
-            if current < 3 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(777)
+    #[test]
+    fn test_collect_errors_history_on_success() {
+        let outcome = (|info: &AttemptInfo<TestError>| match info.attempt {
+            1 => Err(TestError::Retryable),
+            2 => {
+                // The previous error is still visible while it's collected
+                assert_eq!(info.last_error, Some(&TestError::Retryable));
+                Err(TestError::Fatal)
             }
-        };
+            _ => Ok(info.attempt),
+        })
+        .retry_with_info(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .collect_errors()
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("third attempt succeeds");
+
+        let errors: Vec<_> = outcome
+            .history()
+            .unwrap()
+            .iter()
+            .map(|record| (record.attempt, record.error.clone()))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![(1, TestError::Retryable), (2, TestError::Fatal)]
+        );
+
+        // Not collected by default
+        let outcome = (|info: &AttemptInfo<TestError>| match info.attempt {
+            1 => Err(TestError::Retryable),
+            _ => Ok(()),
+        })
+        .retry_with_info(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect("second attempt succeeds");
+        assert!(outcome.history().is_none());
+    }
 
-        let result = operation
-            .with_fibonacci()
-            .call_with_sleeper(FnSleeper(|_| {}));
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cancel_interrupts_sleep() {
+        use crate::cancel::CancellationToken;
+        use core::cell::Cell;
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 4);
-        assert_eq!(outcome.into_inner(), 777);
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        let failed_kind = Cell::new(None);
+        let start = std::time::Instant::now();
+
+        let err = (|| Err::<(), _>("upstream unavailable"))
+            .retry(ConstantBackoff::new().delay_ms(60_000).max_attempts(5))
+            // Shut down while the first retry is pending
+            .notify(move |_| canceller.cancel())
+            .on_failure(|err| failed_kind.set(Some(err.kind())))
+            .cancel_on(token)
+            .call()
+            .expect_err("retry should be cancelled");
+
+        assert!(start.elapsed() < Duration::from_secs(30));
+        assert_eq!(err.kind(), RetryErrorKind::Cancelled);
+        assert_eq!(err.attempts(), 1);
+        assert_eq!(err.cause(), Some(&"upstream unavailable"));
+        assert_eq!(failed_kind.get(), Some(RetryErrorKind::Cancelled));
+        let message = err.to_string();
+        assert!(message.starts_with("retry cancelled after 1 attempts"));
+        assert!(message.ends_with(": upstream unavailable"));
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_with_fibonacci_exhausted() {
-        use super::RetryableExt;
-
-        fn always_fails() -> Result<i32, TestError> {
-            Err(TestError::Retryable)
-        }
+    fn test_cancel_stops_further_attempts() {
+        use crate::cancel::CancellationToken;
+        use core::cell::Cell;
 
-        let result = always_fails
-            .with_fibonacci()
-            .call_with_sleeper(FnSleeper(|_| {}));
+        let token = CancellationToken::new();
+        let attempts = Cell::new(0u32);
+        let sleeps = Cell::new(0u32);
 
-        let err = result.expect_err("retry should exhaust");
-        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
-        assert_eq!(err.attempts(), 8); // Fibonacci default max_attempts
-        assert_eq!(err.max_attempts(), 8);
+        let err = (|| {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() == 2 {
+                token.cancel();
+            }
+            Err::<(), _>(TestError::Retryable)
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .notify(|_| sleeps.set(sleeps.get() + 1))
+        .cancel_on(token.clone())
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect_err("retry should be cancelled");
+
+        assert_eq!(err.kind(), RetryErrorKind::Cancelled);
+        assert_eq!(err.attempts(), 2);
+        assert_eq!(attempts.get(), 2);
+        assert_eq!(sleeps.get(), 1);
     }
 
     #[test]
-    fn test_with_fibonacci_chaining() {
-        use super::RetryableExt;
+    fn test_budget_exhausted_fails_fast() {
+        use crate::budget::RetryBudget;
         use core::cell::Cell;
-        use core::sync::atomic::{AtomicUsize, Ordering};
 
-        static SUCCESS_COUNT: AtomicUsize = AtomicUsize::new(0);
-        let attempts = Cell::new(0);
+        let budget = RetryBudget::new(1, 0.1);
+        let retries = Cell::new(0u32);
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        let err = (|| Err::<(), _>("upstream unavailable"))
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+            .notify(|_| retries.set(retries.get() + 1))
+            .budget(budget.clone())
+            .call_with_sleeper(FnSleeper(|_| {}))
+            .expect_err("budget should run out");
+
+        assert_eq!(err.kind(), RetryErrorKind::BudgetExhausted);
+        assert_eq!(err.attempts(), 2);
+        assert_eq!(retries.get(), 1);
+        assert_eq!(err.cause(), Some(&"upstream unavailable"));
+        assert!(err
+            .to_string()
+            .starts_with("retry budget exhausted after 2 attempts"));
+        assert_eq!(budget.available(), 0.0);
+    }
 
-            if current < 2 {
+    #[test]
+    fn test_budget_refilled_by_successes() {
+        use crate::budget::RetryBudget;
+
+        let budget = RetryBudget::new(1, 0.5);
+        assert!(budget.try_withdraw());
+
+        for _ in 0..2 {
+            let outcome = (|| Ok::<_, TestError>(()))
+                .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+                .budget(budget.clone())
+                .call_with_sleeper(FnSleeper(|_| {}))
+                .unwrap();
+            assert_eq!(outcome.attempts(), 1);
+        }
+        assert_eq!(budget.available(), 1.0);
+
+        let mut attempts = 0;
+        let outcome = (|| {
+            attempts += 1;
+            if attempts == 1 {
                 Err(TestError::Retryable)
             } else {
-                Ok(555)
+                Ok(())
             }
-        };
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .budget(budget.clone())
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .unwrap();
+        assert_eq!(outcome.attempts(), 2);
+        assert_eq!(budget.available(), 0.5);
+    }
 
-        SUCCESS_COUNT.store(0, Ordering::SeqCst);
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_circuit_open_skips_operation() {
+        use crate::circuit::{CircuitBreaker, CircuitState};
+        use core::cell::Cell;
 
-        // Test that .with_fibonacci() can be chained with callbacks
-        let result = operation
-            .with_fibonacci()
-            .on_success(|_ctx| {
-                SUCCESS_COUNT.fetch_add(1, Ordering::SeqCst);
-            })
-            .call_with_sleeper(FnSleeper(|_| {}));
+        let breaker = CircuitBreaker::new(ConstantBackoff::new().delay_ms(60_000))
+            .window(4)
+            .min_calls(3);
+        let calls = Cell::new(0u32);
+        let failed_kind = Cell::new(None);
+
+        let err = (|| {
+            calls.set(calls.get() + 1);
+            Err::<(), _>("upstream unavailable")
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .on_failure(|err| failed_kind.set(Some(err.kind())))
+        .circuit_breaker(breaker.clone())
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect_err("circuit should open");
+
+        assert_eq!(err.kind(), RetryErrorKind::CircuitOpen);
+        assert_eq!(err.attempts(), 3);
+        assert_eq!(err.cause(), Some(&"upstream unavailable"));
+        assert_eq!(calls.get(), 3);
+        assert_eq!(failed_kind.get(), Some(RetryErrorKind::CircuitOpen));
+        assert!(err.to_string().starts_with("circuit open after 3 attempts"));
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // Later calls fail without running the operation
+        let outcome = (|| {
+            calls.set(calls.get() + 1);
+            Ok::<_, &str>(1)
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .circuit_breaker(breaker)
+        .fallback(|err| {
+            assert_eq!(err.attempts(), 0);
+            assert_eq!(err.cause(), None);
+            Ok(0)
+        })
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .unwrap();
+        assert!(outcome.used_fallback());
+        assert_eq!(outcome.into_inner(), 0);
+        assert_eq!(calls.get(), 3);
+    }
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 3);
-        assert_eq!(outcome.into_inner(), 555);
-        assert_eq!(SUCCESS_COUNT.load(Ordering::SeqCst), 1);
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_circuit_probe_success_closes() {
+        use crate::circuit::{CircuitBreaker, CircuitState};
+        use crate::clock::ManualClock;
+
+        let clock = ManualClock::new();
+        let breaker =
+            CircuitBreaker::new(ConstantBackoff::new().delay_ms(1_000).jitter_factor(0.0))
+                .min_calls(1)
+                .clock(clock.clone());
+        breaker.record_failure();
+        clock.advance(Duration::from_secs(1));
+
+        let mut attempts = 0;
+        let outcome = (|| {
+            attempts += 1;
+            Ok::<_, TestError>(attempts)
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .circuit_breaker(breaker.clone())
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .unwrap();
+
+        assert_eq!(outcome.into_inner(), 1);
+        assert_eq!(breaker.state(), CircuitState::Closed);
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_all_extension_methods_produce_working_retries() {
-        use super::RetryableExt;
-        use core::cell::Cell;
+    fn test_circuit_probe_released_when_operation_panics() {
+        use crate::circuit::{CircuitBreaker, CircuitState};
+        use crate::clock::ManualClock;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let clock = ManualClock::new();
+        let breaker =
+            CircuitBreaker::new(ConstantBackoff::new().delay_ms(1_000).jitter_factor(0.0))
+                .min_calls(1)
+                .clock(clock.clone());
+        breaker.record_failure();
+        clock.advance(Duration::from_secs(1));
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            (|| -> Result<(), TestError> { panic!("probe panicked") })
+                .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+                .circuit_breaker(breaker.clone())
+                .call_with_sleeper(FnSleeper(|_| {}))
+        }));
+        assert!(result.is_err());
 
-        let attempts_exp = Cell::new(0);
-        let op_exp = || {
-            let current = attempts_exp.get();
-            attempts_exp.set(current + 1);
-            if current < 1 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(1)
-            }
-        };
+        // The abandoned probe counts as a failed call instead of holding the slot
+        assert_eq!(breaker.state(), CircuitState::Open);
+        clock.advance(Duration::from_secs(1));
+        assert!(breaker.try_acquire().is_some());
+    }
 
-        let attempts_const = Cell::new(0);
-        let op_const = || {
-            let current = attempts_const.get();
-            attempts_const.set(current + 1);
-            if current < 1 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(2)
-            }
-        };
+    #[cfg(all(feature = "async", feature = "std"))]
+    #[tokio::test]
+    async fn test_circuit_probe_released_when_future_dropped() {
+        use crate::circuit::{CircuitBreaker, CircuitState};
+        use crate::clock::ManualClock;
+
+        let clock = ManualClock::new();
+        let breaker =
+            CircuitBreaker::new(ConstantBackoff::new().delay_ms(1_000).jitter_factor(0.0))
+                .min_calls(1)
+                .clock(clock.clone());
+        breaker.record_failure();
+        clock.advance(Duration::from_secs(1));
+
+        let call = (|| core::future::pending::<Result<(), TestError>>())
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+            .circuit_breaker(breaker.clone())
+            .call_async(RecordingAsyncSleeper::default());
+        let timed_out = tokio::time::timeout(Duration::from_millis(10), call).await;
+        assert!(timed_out.is_err());
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+        clock.advance(Duration::from_secs(1));
+        assert!(breaker.try_acquire().is_some());
+    }
 
-        let attempts_fib = Cell::new(0);
-        let op_fib = || {
-            let current = attempts_fib.get();
-            attempts_fib.set(current + 1);
-            if current < 1 {
-                Err(TestError::Retryable)
-            } else {
-                Ok(3)
+    #[test]
+    fn test_operation_receives_attempt_info() {
+        let time = SimulatedTime::default();
+        let mut seen = Vec::new();
+
+        let outcome = (|info: &AttemptInfo<TestError>| {
+            seen.push((
+                info.attempt,
+                info.elapsed,
+                info.remaining,
+                info.last_error.cloned(),
+            ));
+            match info.attempt {
+                1 => Err(TestError::Retryable),
+                2 => Err(TestError::Fatal),
+                _ => Ok(info.attempt),
             }
-        };
+        })
+        .retry_with_info(ConstantBackoff::new().delay_ms(100).max_attempts(5))
+        .max_elapsed(Duration::from_secs(1))
+        .clock(time.clone())
+        .call_with_sleeper(time.clone())
+        .expect("third attempt succeeds");
+
+        assert_eq!(outcome.into_inner(), 3);
+        assert_eq!(
+            seen,
+            vec![
+                (1, Duration::ZERO, Some(Duration::from_secs(1)), None),
+                (
+                    2,
+                    Duration::from_millis(100),
+                    Some(Duration::from_millis(900)),
+                    Some(TestError::Retryable)
+                ),
+                (
+                    3,
+                    Duration::from_millis(200),
+                    Some(Duration::from_millis(800)),
+                    Some(TestError::Fatal)
+                ),
+            ]
+        );
+    }
 
-        // All extension methods should produce working retry builders
-        let r1 = op_exp
-            .with_exponential()
-            .call_with_sleeper(FnSleeper(|_| {}))
-            .expect("exponential retry works");
+    #[test]
+    fn test_attempt_info_without_budget() {
+        let err = (|info: &AttemptInfo<TestError>| {
+            assert_eq!(info.remaining, None);
+            Err::<(), _>(TestError::Retryable)
+        })
+        .retry_with_info(ConstantBackoff::new().delay_ms(10).max_attempts(2))
+        .when(|e| matches!(e, TestError::Retryable))
+        .call_with_sleeper(FnSleeper(|_| {}))
+        .expect_err("retry should exhaust");
 
-        let r2 = op_const
-            .with_constant(100)
-            .call_with_sleeper(FnSleeper(|_| {}))
-            .expect("constant retry works");
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 2);
+    }
 
-        let r3 = op_fib
-            .with_fibonacci()
-            .call_with_sleeper(FnSleeper(|_| {}))
-            .expect("fibonacci retry works");
+    #[cfg(feature = "async")]
+    #[derive(Clone, Default)]
+    struct RecordingAsyncSleeper(std::sync::Arc<std::sync::Mutex<Vec<Duration>>>);
 
-        assert_eq!(r1.into_inner(), 1);
-        assert_eq!(r2.into_inner(), 2);
-        assert_eq!(r3.into_inner(), 3);
+    #[cfg(feature = "async")]
+    #[async_trait::async_trait]
+    impl AsyncSleeper for RecordingAsyncSleeper {
+        async fn sleep_ms(&self, ms: u64) {
+            self.sleep(Duration::from_millis(ms)).await;
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            self.0.lock().unwrap().push(duration);
+            tokio::task::yield_now().await;
+        }
     }
 
+    fn assert_send<T: Send>(_: &T) {}
+
     #[cfg(feature = "std")]
     #[test]
-    fn test_with_exponential_std_sleeper() {
-        use super::RetryableExt;
-        use core::cell::Cell;
+    fn test_send_builder_runs_on_another_thread() {
+        use crate::classify::Classification;
+        use crate::clock::ManualClock;
+        use std::sync::{Arc, Mutex};
+
+        let retried = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&retried);
+        // Hooks only need to be `Send`: a moved `Cell` is fine
+        let failures = core::cell::Cell::new(0);
+
+        let builder = (|| Err::<u32, _>(TestError::Retryable))
+            .retry_send(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .when(|e| *e == TestError::Retryable)
+            .classify(|_: &TestError| Classification::Retry)
+            .notify(move |ctx| sink.lock().unwrap().push(ctx.attempt))
+            .on_success(|_| {})
+            .on_failure(move |_| failures.set(failures.get() + 1))
+            .fallback(|_| Ok(7))
+            .clock(ManualClock::new())
+            .stop_when(crate::stop::after_attempts(3))
+            .retry_if_ok(|value| *value == 0)
+            .cancel_on(crate::cancel::CancellationToken::new());
+        assert_send(&builder);
+
+        let outcome = std::thread::spawn(move || builder.call_with_sleeper(FnSleeper(|_| {})))
+            .join()
+            .unwrap()
+            .expect("fallback replaces the failure");
+
+        assert!(outcome.used_fallback());
+        assert_eq!(outcome.into_inner(), 7);
+        assert_eq!(*retried.lock().unwrap(), vec![1, 2]);
+    }
 
-        let attempts = Cell::new(0);
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_call_async_retries_until_success() {
+        use core::cell::{Cell, RefCell};
 
-        let operation = || {
-            let current = attempts.get();
-            attempts.set(current + 1);
+        let attempts = Cell::new(0u32);
+        let notified = RefCell::new(Vec::new());
+        let succeeded = Cell::new(0u32);
+        let sleeper = RecordingAsyncSleeper::default();
 
-            if current < 1 {
+        let outcome = (async || {
+            attempts.set(attempts.get() + 1);
+            tokio::task::yield_now().await;
+            if attempts.get() < 3 {
                 Err(TestError::Retryable)
             } else {
-                Ok(999)
+                Ok(attempts.get())
             }
-        };
+        })
+        .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+        .notify(|ctx| notified.borrow_mut().push(ctx.attempt))
+        .on_success(|ctx| succeeded.set(ctx.attempt))
+        .call_async(sleeper.clone())
+        .await
+        .expect("retry should succeed");
 
-        // Test with std sleeper
-        let result = operation.with_exponential().call();
+        assert_eq!(outcome.attempts(), 3);
+        assert_eq!(outcome.into_inner(), 3);
+        assert_eq!(*notified.borrow(), vec![1, 2]);
+        assert_eq!(succeeded.get(), 3);
+        assert_eq!(
+            *sleeper.0.lock().unwrap(),
+            vec![Duration::from_millis(10); 2]
+        );
+    }
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.attempts(), 2);
-        assert_eq!(outcome.into_inner(), 999);
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_call_async_failure_matches_sync_path() {
+        use core::cell::Cell;
+
+        let failed_kind = Cell::new(None);
+
+        let err = (|| async { Err::<(), _>(TestError::Retryable) })
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .on_failure(|err| failed_kind.set(Some(err.kind())))
+            .call_async(RecordingAsyncSleeper::default())
+            .await
+            .expect_err("retry should exhaust");
+
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(err.attempts(), 3);
+        assert_eq!(err.cumulative_delay(), Duration::from_millis(20));
+        assert_eq!(failed_kind.get(), Some(RetryErrorKind::Exhausted));
+
+        let err = (|| async { Err::<(), _>(TestError::Fatal) })
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .when(|e| matches!(e, TestError::Retryable))
+            .call_async(RecordingAsyncSleeper::default())
+            .await
+            .expect_err("predicate should stop retries");
+
+        assert_eq!(
+            err.kind(),
+            RetryErrorKind::PredicateRejected(Rejection::Predicate)
+        );
+        assert_eq!(err.into_cause(), Some(TestError::Fatal));
     }
 
-    #[cfg(feature = "std")]
-    #[test]
-    fn test_with_constant_std_sleeper() {
-        use super::RetryableExt;
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_call_async_cancel_interrupts_sleep() {
+        use crate::cancel::CancellationToken;
 
-        fn always_succeeds() -> Result<i32, TestError> {
-            Ok(888)
+        struct NeverWakes;
+
+        #[async_trait::async_trait]
+        impl AsyncSleeper for NeverWakes {
+            async fn sleep_ms(&self, _ms: u64) {
+                core::future::pending::<()>().await;
+            }
         }
 
-        // Test with std sleeper
-        let result = always_succeeds.with_constant(50).call();
+        let token = CancellationToken::new();
+        let canceller = token.clone();
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.into_inner(), 888);
+        let err = (|| async { Err::<(), _>(TestError::Retryable) })
+            .retry(ConstantBackoff::new().delay_ms(10).max_attempts(5))
+            .notify(move |_| canceller.cancel())
+            .cancel_on(token)
+            .call_async(NeverWakes)
+            .await
+            .expect_err("retry should be cancelled");
+
+        assert_eq!(err.kind(), RetryErrorKind::Cancelled);
+        assert_eq!(err.attempts(), 1);
+        assert_eq!(err.into_cause(), Some(TestError::Retryable));
     }
 
-    #[cfg(feature = "std")]
-    #[test]
-    fn test_with_fibonacci_std_sleeper() {
-        use super::RetryableExt;
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_call_async_receives_attempt_info() {
+        let mut attempts = Vec::new();
 
-        fn always_succeeds() -> Result<i32, TestError> {
-            Ok(444)
-        }
+        let outcome = (async |info: &AttemptInfo<'_, TestError>| {
+            tokio::task::yield_now().await;
+            attempts.push((info.attempt, info.last_error.cloned()));
+            if info.attempt < 2 {
+                Err(TestError::Retryable)
+            } else {
+                Ok("done")
+            }
+        })
+        .retry_with_info(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+        .call_async(RecordingAsyncSleeper::default())
+        .await
+        .expect("second attempt succeeds");
 
-        // Test with std sleeper
-        let result = always_succeeds.with_fibonacci().call();
+        assert_eq!(outcome.attempts(), 2);
+        assert_eq!(attempts, vec![(1, None), (2, Some(TestError::Retryable))]);
+    }
 
-        let outcome = result.expect("retry should succeed");
-        assert_eq!(outcome.into_inner(), 444);
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_send_builder_runs_in_spawned_task() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Arc;
+
+        let retries = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&retries);
+
+        let builder = (|| async { Err::<(), _>(TestError::Retryable) })
+            .retry_send(ConstantBackoff::new().delay_ms(10).max_attempts(3))
+            .notify(move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .cancel_on(crate::cancel::CancellationToken::new());
+        assert_send(&builder);
+
+        let err = tokio::spawn(builder.call_async(RecordingAsyncSleeper::default()))
+            .await
+            .unwrap()
+            .expect_err("retries run out");
+
+        assert_eq!(err.kind(), RetryErrorKind::Exhausted);
+        assert_eq!(retries.load(Ordering::Relaxed), 2);
     }
 }
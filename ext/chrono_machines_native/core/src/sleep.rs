@@ -3,6 +3,12 @@
 //! This module provides traits and implementations for sleeping/delaying
 //! in various environments (std, async, embedded).
 
+use crate::backoff::duration_as_ms;
+use core::time::Duration;
+
+#[cfg(all(not(feature = "std"), feature = "async"))]
+use alloc::boxed::Box;
+
 /// Trait for sleep/delay implementations
 ///
 /// This trait abstracts sleep operations to support different runtime environments:
@@ -13,6 +19,21 @@
 pub trait Sleeper {
     /// Sleep for the specified number of milliseconds
     fn sleep_ms(&self, ms: u64);
+
+    /// Sleep for the specified duration
+    ///
+    /// The retry loop always calls this method. The default implementation
+    /// rounds up to whole milliseconds and calls [`Sleeper::sleep_ms`], so
+    /// sub-millisecond delays are never skipped; sleepers backed by a finer
+    /// timer should override it.
+    fn sleep(&self, duration: Duration) {
+        let ms = duration_as_ms(duration);
+        if duration > Duration::from_millis(ms) {
+            self.sleep_ms(ms.saturating_add(1));
+        } else {
+            self.sleep_ms(ms);
+        }
+    }
 }
 
 /// Standard library sleeper using `std::thread::sleep`
@@ -27,6 +48,7 @@ pub trait Sleeper {
 ///
 /// let sleeper = StdSleeper;
 /// sleeper.sleep_ms(100); // Sleep for 100ms
+/// sleeper.sleep(std::time::Duration::from_micros(250)); // Sub-millisecond sleep
 /// ```
 #[cfg(feature = "std")]
 #[derive(Debug, Clone, Copy)]
@@ -35,19 +57,25 @@ pub struct StdSleeper;
 #[cfg(feature = "std")]
 impl Sleeper for StdSleeper {
     fn sleep_ms(&self, ms: u64) {
-        std::thread::sleep(std::time::Duration::from_millis(ms));
+        std::thread::sleep(Duration::from_millis(ms));
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
     }
 }
 
-/// Function pointer sleeper for custom sleep implementations
+/// Closure sleeper for custom sleep implementations
 ///
-/// Wraps a function pointer that takes milliseconds and performs sleep.
-/// Useful for async runtimes or testing.
+/// Wraps a function or closure that takes milliseconds and performs sleep.
+/// Closures may capture state, such as a channel to a scheduler that owns
+/// all waiting, a clock, or a metrics handle.
 ///
 /// # Example
 ///
 /// ```rust
 /// use chrono_machines::sleep::{FnSleeper, Sleeper};
+/// use std::sync::mpsc;
 ///
 /// // Custom sleep function
 /// fn my_sleep(ms: u64) {
@@ -57,16 +85,407 @@ impl Sleeper for StdSleeper {
 ///
 /// let sleeper = FnSleeper(my_sleep);
 /// sleeper.sleep_ms(100);
+///
+/// // Hand waits to a scheduler instead of sleeping in place
+/// let (scheduler, requests) = mpsc::channel();
+/// let sleeper = FnSleeper(move |ms| scheduler.send(ms).unwrap());
+/// sleeper.sleep_ms(250);
+/// assert_eq!(requests.recv().unwrap(), 250);
 /// ```
 #[derive(Clone, Copy)]
-pub struct FnSleeper(pub fn(u64));
+pub struct FnSleeper<F = fn(u64)>(pub F);
 
-impl Sleeper for FnSleeper {
+impl<F: Fn(u64)> Sleeper for FnSleeper<F> {
     fn sleep_ms(&self, ms: u64) {
         (self.0)(ms);
     }
 }
 
+impl<S: Sleeper + ?Sized> Sleeper for &S {
+    fn sleep_ms(&self, ms: u64) {
+        (**self).sleep_ms(ms);
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Sleeper + ?Sized> Sleeper for alloc::boxed::Box<S> {
+    fn sleep_ms(&self, ms: u64) {
+        (**self).sleep_ms(ms);
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Sleeper + ?Sized> Sleeper for alloc::sync::Arc<S> {
+    fn sleep_ms(&self, ms: u64) {
+        (**self).sleep_ms(ms);
+    }
+
+    fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration);
+    }
+}
+
+/// Sleeper that records every requested delay without sleeping (requires `std` feature)
+///
+/// Meant for tests that assert on the delay schedule. Clones share the same
+/// record, so hand one clone to the retry loop and inspect another.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::sleep::RecordingSleeper;
+/// use chrono_machines::{ConstantBackoff, Retryable};
+/// use core::time::Duration;
+///
+/// let sleeper = RecordingSleeper::new();
+/// let _ = (|| Err::<(), _>("unavailable"))
+///     .retry(ConstantBackoff::new().delay_ms(100).max_attempts(3))
+///     .call_with_sleeper(sleeper.clone());
+///
+/// assert_eq!(sleeper.delays(), vec![Duration::from_millis(100); 2]);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct RecordingSleeper {
+    delays: std::sync::Arc<std::sync::Mutex<Vec<Duration>>>,
+}
+
+#[cfg(feature = "std")]
+impl RecordingSleeper {
+    /// Create a sleeper with an empty record
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Delays requested so far, in order
+    pub fn delays(&self) -> Vec<Duration> {
+        self.lock().clone()
+    }
+
+    /// Sum of all delays requested so far
+    pub fn total(&self) -> Duration {
+        self.lock()
+            .iter()
+            .fold(Duration::ZERO, |total, delay| total.saturating_add(*delay))
+    }
+
+    /// Forget the recorded delays
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn record(&self, duration: Duration) {
+        self.lock().push(duration);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Duration>> {
+        self.delays
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Sleeper for RecordingSleeper {
+    fn sleep_ms(&self, ms: u64) {
+        self.record(Duration::from_millis(ms));
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.record(duration);
+    }
+}
+
+/// Sleeper that advances a [`ManualClock`] instead of sleeping (requires `std` feature)
+///
+/// Pair it with the same clock passed to
+/// [`RetryBuilder::clock`](crate::retry::RetryBuilder::clock) to exercise
+/// elapsed-time budgets and stop conditions instantly.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::clock::{Clock, ManualClock};
+/// use chrono_machines::sleep::VirtualSleeper;
+/// use chrono_machines::{ConstantBackoff, Retryable, RetryErrorKind};
+/// use core::time::Duration;
+///
+/// let clock = ManualClock::new();
+/// let err = (|| Err::<(), _>("unavailable"))
+///     .retry(ConstantBackoff::new().delay_ms(1_000).max_attempts(100))
+///     .max_elapsed(Duration::from_secs(10))
+///     .clock(clock.clone())
+///     .call_with_sleeper(VirtualSleeper::new(clock.clone()))
+///     .unwrap_err();
+///
+/// assert_eq!(err.kind(), RetryErrorKind::DeadlineExceeded);
+/// assert_eq!(clock.now(), Duration::from_secs(10));
+/// ```
+///
+/// [`ManualClock`]: crate::clock::ManualClock
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct VirtualSleeper {
+    clock: crate::clock::ManualClock,
+}
+
+#[cfg(feature = "std")]
+impl VirtualSleeper {
+    /// Create a sleeper that advances `clock`
+    pub fn new(clock: crate::clock::ManualClock) -> Self {
+        Self { clock }
+    }
+
+    /// The clock this sleeper advances
+    pub fn clock(&self) -> &crate::clock::ManualClock {
+        &self.clock
+    }
+}
+
+#[cfg(feature = "std")]
+impl Sleeper for VirtualSleeper {
+    fn sleep_ms(&self, ms: u64) {
+        self.clock.advance(Duration::from_millis(ms));
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+}
+
+/// Trait for asynchronous sleep implementations (requires `async` feature)
+///
+/// The async counterpart of [`Sleeper`], used by
+/// [`RetryBuilder::call_async`](crate::retry::RetryBuilder::call_async) so
+/// waiting between attempts yields to the executor instead of blocking a
+/// thread. Sleep futures are boxed and `Send`, so implementations must be
+/// `Sync`.
+///
+/// Ready-made implementations: [`TokioSleeper`] (`tokio` feature),
+/// [`AsyncStdSleeper`] (`async-std`), [`SmolSleeper`] (`smol`) and
+/// [`EmbassySleeper`] (`embassy`).
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::sleep::AsyncSleeper;
+/// use core::time::Duration;
+///
+/// // Sleeper for a custom executor's timer
+/// struct ExecutorSleeper;
+///
+/// #[async_trait::async_trait]
+/// impl AsyncSleeper for ExecutorSleeper {
+///     async fn sleep_ms(&self, ms: u64) {
+///         // my_executor::timer(Duration::from_millis(ms)).await
+/// #       let _ = Duration::from_millis(ms);
+///     }
+/// }
+/// ```
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncSleeper: Sync {
+    /// Sleep for the specified number of milliseconds
+    async fn sleep_ms(&self, ms: u64);
+
+    /// Sleep for the specified duration
+    ///
+    /// Like [`Sleeper::sleep`], the default implementation rounds up to whole
+    /// milliseconds and calls [`AsyncSleeper::sleep_ms`].
+    async fn sleep(&self, duration: Duration) {
+        let ms = duration_as_ms(duration);
+        if duration > Duration::from_millis(ms) {
+            self.sleep_ms(ms.saturating_add(1)).await;
+        } else {
+            self.sleep_ms(ms).await;
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<S: AsyncSleeper + ?Sized> AsyncSleeper for &S {
+    async fn sleep_ms(&self, ms: u64) {
+        (**self).sleep_ms(ms).await;
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration).await;
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<S: AsyncSleeper + ?Sized> AsyncSleeper for alloc::boxed::Box<S> {
+    async fn sleep_ms(&self, ms: u64) {
+        (**self).sleep_ms(ms).await;
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration).await;
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<S: AsyncSleeper + Send + ?Sized> AsyncSleeper for alloc::sync::Arc<S> {
+    async fn sleep_ms(&self, ms: u64) {
+        (**self).sleep_ms(ms).await;
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        (**self).sleep(duration).await;
+    }
+}
+
+#[cfg(all(feature = "async", feature = "std"))]
+#[async_trait::async_trait]
+impl AsyncSleeper for RecordingSleeper {
+    async fn sleep_ms(&self, ms: u64) {
+        self.record(Duration::from_millis(ms));
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        self.record(duration);
+    }
+}
+
+#[cfg(all(feature = "async", feature = "std"))]
+#[async_trait::async_trait]
+impl AsyncSleeper for VirtualSleeper {
+    async fn sleep_ms(&self, ms: u64) {
+        self.clock.advance(Duration::from_millis(ms));
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+}
+
+/// Tokio sleeper using `tokio::time::sleep`
+///
+/// Only available when the `tokio` feature is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono_machines::sleep::TokioSleeper;
+/// use chrono_machines::{AsyncRetryable, ExponentialBackoff};
+///
+/// async fn fetch_data() -> Result<String, std::io::Error> {
+///     // ...
+/// #   Ok("data".to_string())
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), chrono_machines::RetryError<std::io::Error>> {
+/// let outcome = fetch_data
+///     .retry(ExponentialBackoff::default())
+///     .call_async(TokioSleeper)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+#[cfg(feature = "tokio")]
+#[async_trait::async_trait]
+impl AsyncSleeper for TokioSleeper {
+    async fn sleep_ms(&self, ms: u64) {
+        tokio::time::sleep(Duration::from_millis(ms)).await;
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// async-std sleeper using `async_std::task::sleep`
+///
+/// Only available when the `async-std` feature is enabled.
+#[cfg(feature = "async-std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncStdSleeper;
+
+#[cfg(feature = "async-std")]
+#[async_trait::async_trait]
+impl AsyncSleeper for AsyncStdSleeper {
+    async fn sleep_ms(&self, ms: u64) {
+        async_std::task::sleep(Duration::from_millis(ms)).await;
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        async_std::task::sleep(duration).await;
+    }
+}
+
+/// smol sleeper using `smol::Timer`
+///
+/// Only available when the `smol` feature is enabled.
+#[cfg(feature = "smol")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SmolSleeper;
+
+#[cfg(feature = "smol")]
+#[async_trait::async_trait]
+impl AsyncSleeper for SmolSleeper {
+    async fn sleep_ms(&self, ms: u64) {
+        smol::Timer::after(Duration::from_millis(ms)).await;
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        smol::Timer::after(duration).await;
+    }
+}
+
+/// Embassy sleeper using `embassy_time::Timer` for `no_std` async firmware
+///
+/// Only available when the `embassy` feature is enabled. Requires an
+/// `embassy-time` driver in the final binary, like any other Embassy timer.
+/// Durations are rounded up to whole microseconds.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use chrono_machines::sleep::EmbassySleeper;
+/// use chrono_machines::{AsyncRetryable, ExponentialBackoff};
+///
+/// let outcome = (|| async { sensor.read().await })
+///     .retry(ExponentialBackoff::default().max_attempts(5))
+///     .call_async_with_rng(EmbassySleeper, rng)
+///     .await;
+/// ```
+#[cfg(feature = "embassy")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbassySleeper;
+
+#[cfg(feature = "embassy")]
+#[async_trait::async_trait]
+impl AsyncSleeper for EmbassySleeper {
+    async fn sleep_ms(&self, ms: u64) {
+        embassy_time::Timer::after_millis(ms).await;
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        let mut micros = duration.as_micros();
+        if duration > Duration::from_micros(micros as u64) {
+            micros += 1;
+        }
+        let micros = u64::try_from(micros).unwrap_or(u64::MAX);
+        embassy_time::Timer::after_micros(micros).await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,12 +505,177 @@ mod tests {
     #[test]
     fn test_fn_sleeper() {
         fn test_sleep(ms: u64) {
-            // In a real test, we'd need interior mutability
-            // For this test, we just verify it compiles and runs
+            // Stateless: RecordingSleeper covers delay assertions
             assert!(ms > 0);
         }
 
         let sleeper = FnSleeper(test_sleep);
         sleeper.sleep_ms(100);
     }
+
+    #[test]
+    fn test_closure_sleeper_captures_state() {
+        use core::cell::RefCell;
+
+        let requested = RefCell::new(Vec::new());
+        let sleeper = FnSleeper(|ms| requested.borrow_mut().push(ms));
+        sleeper.sleep_ms(10);
+        sleeper.sleep(Duration::from_micros(1500));
+
+        assert_eq!(*requested.borrow(), vec![10, 2]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_pointer_sleepers_forward() {
+        use std::sync::Arc;
+
+        fn sleep_once<S: Sleeper>(sleeper: S) {
+            sleeper.sleep(Duration::from_millis(5));
+        }
+
+        let recording = RecordingSleeper::new();
+        sleep_once(&recording);
+        sleep_once(Box::new(recording.clone()) as Box<dyn Sleeper>);
+        let shared = Arc::new(recording.clone());
+        sleep_once(Arc::clone(&shared));
+        sleep_once(&shared);
+
+        assert_eq!(recording.delays(), vec![Duration::from_millis(5); 4]);
+    }
+
+    #[test]
+    fn test_default_sleep_rounds_up() {
+        use core::sync::atomic::{AtomicU64, Ordering};
+
+        static SLEPT: AtomicU64 = AtomicU64::new(0);
+        fn record(ms: u64) {
+            SLEPT.store(ms, Ordering::SeqCst);
+        }
+
+        let sleeper = FnSleeper(record);
+        sleeper.sleep(Duration::from_micros(100));
+        assert_eq!(SLEPT.load(Ordering::SeqCst), 1);
+
+        sleeper.sleep(Duration::from_millis(25));
+        assert_eq!(SLEPT.load(Ordering::SeqCst), 25);
+
+        sleeper.sleep(Duration::ZERO);
+        assert_eq!(SLEPT.load(Ordering::SeqCst), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_recording_sleeper() {
+        let sleeper = RecordingSleeper::new();
+        let handle = sleeper.clone();
+        Sleeper::sleep(&sleeper, Duration::from_micros(250));
+        Sleeper::sleep_ms(&sleeper, 40);
+
+        assert_eq!(
+            handle.delays(),
+            vec![Duration::from_micros(250), Duration::from_millis(40)]
+        );
+        assert_eq!(handle.total(), Duration::from_micros(40_250));
+
+        handle.clear();
+        assert!(sleeper.delays().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_virtual_sleeper_advances_clock() {
+        use crate::clock::{Clock, ManualClock};
+
+        let clock = ManualClock::new();
+        let sleeper = VirtualSleeper::new(clock.clone());
+        let start = std::time::Instant::now();
+        Sleeper::sleep(&sleeper, Duration::from_secs(3600));
+        Sleeper::sleep_ms(&sleeper, 500);
+
+        assert_eq!(clock.now(), Duration::from_millis(3_600_500));
+        assert_eq!(sleeper.clock().now(), clock.now());
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_test_sleepers() {
+        use crate::clock::{Clock, ManualClock};
+
+        let recording = RecordingSleeper::new();
+        AsyncSleeper::sleep(&recording, Duration::from_millis(30)).await;
+        AsyncSleeper::sleep_ms(&recording, 10).await;
+        assert_eq!(recording.total(), Duration::from_millis(40));
+
+        let clock = ManualClock::new();
+        let sleeper = VirtualSleeper::new(clock.clone());
+        AsyncSleeper::sleep(&sleeper, Duration::from_secs(60)).await;
+        assert_eq!(clock.now(), Duration::from_secs(60));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_pointer_sleepers_forward() {
+        use std::sync::Arc;
+
+        let recording = RecordingSleeper::new();
+        AsyncSleeper::sleep(&&recording, Duration::from_millis(5)).await;
+        let boxed: Box<dyn AsyncSleeper> = Box::new(recording.clone());
+        boxed.sleep(Duration::from_millis(5)).await;
+        let shared: Arc<dyn AsyncSleeper + Send> = Arc::new(recording.clone());
+        shared.sleep(Duration::from_millis(5)).await;
+
+        assert_eq!(recording.total(), Duration::from_millis(15));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_default_sleep_rounds_up() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<u64>>);
+
+        #[async_trait::async_trait]
+        impl AsyncSleeper for Recorder {
+            async fn sleep_ms(&self, ms: u64) {
+                self.0.lock().unwrap().push(ms);
+            }
+        }
+
+        let sleeper = Recorder::default();
+        sleeper.sleep(Duration::from_micros(100)).await;
+        sleeper.sleep(Duration::from_millis(25)).await;
+
+        assert_eq!(*sleeper.0.lock().unwrap(), vec![1, 25]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_tokio_sleeper() {
+        let start = std::time::Instant::now();
+        TokioSleeper.sleep(Duration::from_millis(5)).await;
+        TokioSleeper.sleep_ms(5).await;
+
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn test_async_std_sleeper() {
+        let start = std::time::Instant::now();
+        async_std::task::block_on(AsyncStdSleeper.sleep(Duration::from_millis(5)));
+
+        assert!(start.elapsed() >= Duration::from_millis(5));
+    }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn test_smol_sleeper() {
+        let start = std::time::Instant::now();
+        smol::block_on(SmolSleeper.sleep(Duration::from_millis(5)));
+
+        assert!(start.elapsed() >= Duration::from_millis(5));
+    }
 }
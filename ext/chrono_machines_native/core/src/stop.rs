@@ -0,0 +1,266 @@
+//! Stop conditions that decide when a retry loop gives up
+//!
+//! Backoff strategies describe *how long* to wait between attempts. A
+//! [`StopCondition`] decides *whether* to try again, so one schedule can be
+//! paired with different stop rules per call site via
+//! [`RetryBuilder::stop_when`](crate::retry::RetryBuilder::stop_when).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chrono_machines::stop::{self, StopCondition, StopConditionExt, StopState};
+//! use core::time::Duration;
+//!
+//! // Give up after 10 attempts or 30 seconds, whichever comes first
+//! let condition = stop::after_attempts(10).or(stop::after_elapsed(Duration::from_secs(30)));
+//!
+//! let state = StopState {
+//!     attempt: 3,
+//!     elapsed: Duration::from_secs(31),
+//!     cumulative_delay: Duration::from_secs(2),
+//! };
+//! assert!(condition.should_stop(&state));
+//! ```
+
+use core::time::Duration;
+
+/// Retry progress inspected by a [`StopCondition`] after a failed attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StopState {
+    /// Attempt that just failed (1-indexed)
+    pub attempt: u32,
+    /// Time elapsed since the first attempt started
+    pub elapsed: Duration,
+    /// Total time spent sleeping between attempts so far
+    pub cumulative_delay: Duration,
+}
+
+/// Decides whether a retry loop should stop after a failed attempt
+///
+/// Implemented for closures taking a [`StopState`], so ad-hoc rules don't
+/// need a dedicated type.
+pub trait StopCondition {
+    /// Return `true` to stop retrying and report the last error
+    fn should_stop(&self, state: &StopState) -> bool;
+
+    /// Attempt limit implied by this condition, if any
+    ///
+    /// Only used to report [`RetryError::max_attempts`](crate::retry::RetryError::max_attempts).
+    fn max_attempts(&self) -> Option<u32> {
+        None
+    }
+}
+
+impl<F> StopCondition for F
+where
+    F: Fn(&StopState) -> bool,
+{
+    fn should_stop(&self, state: &StopState) -> bool {
+        self(state)
+    }
+}
+
+/// Stop once `max_attempts` attempts have been made
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StopAfterAttempts {
+    /// Total attempts allowed, including the first
+    pub max_attempts: u32,
+}
+
+impl StopCondition for StopAfterAttempts {
+    fn should_stop(&self, state: &StopState) -> bool {
+        state.attempt >= self.max_attempts
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        Some(self.max_attempts)
+    }
+}
+
+/// Stop once the time since the first attempt reaches `limit`
+///
+/// Unlike [`RetryBuilder::max_elapsed`](crate::retry::RetryBuilder::max_elapsed),
+/// the delay before the next attempt is not clipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StopAfterElapsed {
+    /// Elapsed time after which no further attempts are made
+    pub limit: Duration,
+}
+
+impl StopCondition for StopAfterElapsed {
+    fn should_stop(&self, state: &StopState) -> bool {
+        state.elapsed >= self.limit
+    }
+}
+
+/// Stop once the total time spent sleeping reaches `limit`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StopAfterDelay {
+    /// Cumulative sleep after which no further attempts are made
+    pub limit: Duration,
+}
+
+impl StopCondition for StopAfterDelay {
+    fn should_stop(&self, state: &StopState) -> bool {
+        state.cumulative_delay >= self.limit
+    }
+}
+
+/// Never stop; retry until the operation succeeds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StopNever;
+
+impl StopCondition for StopNever {
+    fn should_stop(&self, _state: &StopState) -> bool {
+        false
+    }
+}
+
+/// Stop when either condition says so
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Any<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: StopCondition, B: StopCondition> StopCondition for Any<A, B> {
+    fn should_stop(&self, state: &StopState) -> bool {
+        self.first.should_stop(state) || self.second.should_stop(state)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        match (self.first.max_attempts(), self.second.max_attempts()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// Stop only when both conditions say so
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct All<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: StopCondition, B: StopCondition> StopCondition for All<A, B> {
+    fn should_stop(&self, state: &StopState) -> bool {
+        self.first.should_stop(state) && self.second.should_stop(state)
+    }
+
+    fn max_attempts(&self) -> Option<u32> {
+        // Only a hard limit when both sides have one
+        match (self.first.max_attempts(), self.second.max_attempts()) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        }
+    }
+}
+
+/// Stop after `max_attempts` attempts (including the first)
+pub const fn after_attempts(max_attempts: u32) -> StopAfterAttempts {
+    StopAfterAttempts { max_attempts }
+}
+
+/// Stop once `limit` has elapsed since the first attempt started
+pub const fn after_elapsed(limit: Duration) -> StopAfterElapsed {
+    StopAfterElapsed { limit }
+}
+
+/// Stop once `limit` has been spent sleeping between attempts
+pub const fn after_delay(limit: Duration) -> StopAfterDelay {
+    StopAfterDelay { limit }
+}
+
+/// Never stop retrying
+pub const fn never() -> StopNever {
+    StopNever
+}
+
+/// Stop when either `first` or `second` says so
+pub const fn any<A: StopCondition, B: StopCondition>(first: A, second: B) -> Any<A, B> {
+    Any { first, second }
+}
+
+/// Stop only when both `first` and `second` say so
+pub const fn all<A: StopCondition, B: StopCondition>(first: A, second: B) -> All<A, B> {
+    All { first, second }
+}
+
+/// Combinator methods available on every [`StopCondition`]
+pub trait StopConditionExt: StopCondition + Sized {
+    /// Stop when either this condition or `other` says so
+    fn or<O: StopCondition>(self, other: O) -> Any<Self, O> {
+        any(self, other)
+    }
+
+    /// Stop only when both this condition and `other` say so
+    fn and<O: StopCondition>(self, other: O) -> All<Self, O> {
+        all(self, other)
+    }
+}
+
+impl<S: StopCondition> StopConditionExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(attempt: u32, elapsed_ms: u64, cumulative_delay_ms: u64) -> StopState {
+        StopState {
+            attempt,
+            elapsed: Duration::from_millis(elapsed_ms),
+            cumulative_delay: Duration::from_millis(cumulative_delay_ms),
+        }
+    }
+
+    #[test]
+    fn test_builtin_conditions() {
+        let attempts = after_attempts(3);
+        assert!(!attempts.should_stop(&state(2, 0, 0)));
+        assert!(attempts.should_stop(&state(3, 0, 0)));
+        assert_eq!(attempts.max_attempts(), Some(3));
+
+        let elapsed = after_elapsed(Duration::from_millis(100));
+        assert!(!elapsed.should_stop(&state(9, 99, 500)));
+        assert!(elapsed.should_stop(&state(1, 100, 0)));
+        assert_eq!(elapsed.max_attempts(), None);
+
+        let delay = after_delay(Duration::from_millis(50));
+        assert!(!delay.should_stop(&state(9, 500, 49)));
+        assert!(delay.should_stop(&state(1, 0, 50)));
+
+        assert!(!never().should_stop(&state(u32::MAX, u64::MAX, u64::MAX)));
+    }
+
+    #[test]
+    fn test_any_and_all() {
+        let either = after_attempts(5).or(after_delay(Duration::from_millis(100)));
+        assert!(!either.should_stop(&state(4, 0, 99)));
+        assert!(either.should_stop(&state(5, 0, 0)));
+        assert!(either.should_stop(&state(1, 0, 100)));
+        assert_eq!(either.max_attempts(), Some(5));
+
+        let both = after_attempts(5).and(after_delay(Duration::from_millis(100)));
+        assert!(!both.should_stop(&state(5, 0, 0)));
+        assert!(!both.should_stop(&state(1, 0, 100)));
+        assert!(both.should_stop(&state(5, 0, 100)));
+        assert_eq!(both.max_attempts(), None);
+
+        assert_eq!(
+            any(after_attempts(5), after_attempts(3)).max_attempts(),
+            Some(3)
+        );
+        assert_eq!(
+            all(after_attempts(5), after_attempts(3)).max_attempts(),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn test_closure_condition() {
+        let odd_attempt = |state: &StopState| state.attempt % 2 == 1;
+        assert!(odd_attempt.should_stop(&state(3, 0, 0)));
+        assert!(!odd_attempt.or(never()).should_stop(&state(2, 0, 0)));
+    }
+}
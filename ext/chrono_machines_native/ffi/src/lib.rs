@@ -6,8 +6,8 @@
 #![warn(rust_2024_compatibility)]
 #![warn(clippy::all)]
 
-use chrono_machines::fibonacci;
-use magnus::{function, Error, Ruby};
+use chrono_machines::{fibonacci, Jitter};
+use magnus::{function, Error, RArray, RString, Ruby, Symbol, TryConvert, Value};
 use rand::rngs::StdRng;
 use rand::RngExt;
 use std::cell::RefCell;
@@ -17,6 +17,110 @@ thread_local! {
     static RNG: RefCell<StdRng> = RefCell::new(rand::make_rng());
 }
 
+/// Jitter mode argument accepted from Ruby
+///
+/// Mirrors [`Jitter`] so Ruby and Rust compute identical distributions.
+/// Accepted forms:
+///
+/// * a number - proportional jitter factor (0.0 = no jitter, 1.0 = full jitter)
+/// * `:none`, `:full`, `:equal` (symbols or strings)
+/// * `[:proportional, factor]`
+/// * `[:bounded, min_seconds, max_seconds]`
+/// * `[:gaussian, stddev]` - standard deviation as a fraction of the delay
+#[derive(Debug, Clone, Copy)]
+enum JitterArg {
+    /// One of the core [`Jitter`] modes
+    Mode(Jitter),
+    /// Offset bounds in seconds, applied here rather than through
+    /// [`Jitter::Bounded`] so sub-millisecond bounds aren't rounded
+    Bounded { min: f64, max: f64 },
+}
+
+impl From<f64> for JitterArg {
+    fn from(jitter_factor: f64) -> Self {
+        JitterArg::Mode(Jitter::Proportional(normalize_jitter(jitter_factor)))
+    }
+}
+
+impl TryConvert for JitterArg {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        // Holding a Ruby value proves we are on a Ruby thread
+        let ruby = Ruby::get_with(val);
+
+        if let Some(mode) = jitter_mode_name(val)? {
+            return parse_jitter_mode(&ruby, &mode, None);
+        }
+
+        if let Some(spec) = RArray::from_value(val) {
+            let mode = jitter_mode_name(spec.entry::<Value>(0)?)?
+                .ok_or_else(|| invalid_jitter(&ruby, "jitter mode must be a Symbol or String"))?;
+            return parse_jitter_mode(&ruby, &mode, Some(spec));
+        }
+
+        f64::try_convert(val).map(JitterArg::from)
+    }
+}
+
+/// Extract a jitter mode name from a Symbol or String value
+fn jitter_mode_name(val: Value) -> Result<Option<String>, Error> {
+    if let Some(symbol) = Symbol::from_value(val) {
+        return Ok(Some(symbol.name()?.into_owned()));
+    }
+    if let Some(string) = RString::from_value(val) {
+        return Ok(Some(string.to_string()?));
+    }
+    Ok(None)
+}
+
+/// Build a [`JitterArg`] from its mode name and optional `[mode, args...]` array
+fn parse_jitter_mode(ruby: &Ruby, mode: &str, spec: Option<RArray>) -> Result<JitterArg, Error> {
+    let arg = |index: isize| -> Result<f64, Error> {
+        match spec {
+            Some(spec) if spec.len() > index as usize => spec.entry::<f64>(index),
+            _ => Err(invalid_jitter(
+                ruby,
+                format!("jitter mode :{mode} is missing argument {index}"),
+            )),
+        }
+    };
+
+    match mode {
+        "none" => Ok(JitterArg::Mode(Jitter::None)),
+        "full" => Ok(JitterArg::Mode(Jitter::Full)),
+        "equal" => Ok(JitterArg::Mode(Jitter::Equal)),
+        "proportional" => Ok(JitterArg::Mode(Jitter::Proportional(normalize_jitter(
+            arg(1)?,
+        )))),
+        "bounded" => Ok(JitterArg::Bounded {
+            min: bounded_offset(ruby, arg(1)?)?,
+            max: bounded_offset(ruby, arg(2)?)?,
+        }),
+        "gaussian" => Ok(JitterArg::Mode(Jitter::Gaussian { stddev: arg(1)? })),
+        other => Err(invalid_jitter(
+            ruby,
+            format!("unknown jitter mode :{other}"),
+        )),
+    }
+}
+
+/// Build an `ArgumentError` for a malformed jitter argument
+fn invalid_jitter(ruby: &Ruby, message: impl Into<String>) -> Error {
+    Error::new(ruby.exception_arg_error(), message.into())
+}
+
+/// Validate a bounded jitter offset in seconds, clamping negatives to zero
+///
+/// Non-finite offsets are rejected: no delay can be drawn from such a range.
+fn bounded_offset(ruby: &Ruby, seconds: f64) -> Result<f64, Error> {
+    if !seconds.is_finite() {
+        return Err(invalid_jitter(
+            ruby,
+            format!("bounded jitter offsets must be finite, got {seconds}"),
+        ));
+    }
+    Ok(seconds.max(0.0))
+}
+
 /// Calculate delay using exponential backoff with configurable jitter
 ///
 /// # Arguments
@@ -24,7 +128,7 @@ thread_local! {
 /// * `base_delay` - Base delay in seconds
 /// * `multiplier` - Exponential multiplier
 /// * `max_delay` - Maximum delay cap in seconds
-/// * `jitter_factor` - Jitter multiplier (0.0 = no jitter, 1.0 = full jitter)
+/// * `jitter` - Jitter factor or mode (see [`JitterArg`])
 ///
 /// # Returns
 /// Calculated delay in seconds with jitter applied
@@ -33,16 +137,15 @@ fn calculate_delay_exponential(
     base_delay: f64,
     multiplier: f64,
     max_delay: f64,
-    jitter_factor: f64,
+    jitter: JitterArg,
 ) -> f64 {
-    let jitter_factor = normalize_jitter(jitter_factor);
-    let attempt_u8 = attempt.clamp(1, 255) as u8;
-    let exponent = attempt_u8.saturating_sub(1) as i32;
+    let attempt = attempt.clamp(1, u32::MAX as i64) as u32;
+    let exponent = (attempt - 1).min(i32::MAX as u32) as i32;
 
     let base_exponential = base_delay * multiplier.powi(exponent);
-    let capped = base_exponential.min(max_delay);
+    let capped = cap_delay(base_exponential, max_delay);
 
-    apply_jitter(capped, jitter_factor)
+    apply_jitter(capped, jitter)
 }
 
 /// Calculate delay using constant backoff with optional jitter
@@ -50,13 +153,12 @@ fn calculate_delay_exponential(
 /// # Arguments
 /// * `_attempt` - The current attempt number (unused for constant backoff)
 /// * `delay` - Constant delay in seconds
-/// * `jitter_factor` - Jitter multiplier (0.0 = no jitter, 1.0 = full jitter)
+/// * `jitter` - Jitter factor or mode (see [`JitterArg`])
 ///
 /// # Returns
 /// Constant delay with jitter applied
-fn calculate_delay_constant(_attempt: i64, delay: f64, jitter_factor: f64) -> f64 {
-    let jitter_factor = normalize_jitter(jitter_factor);
-    apply_jitter(delay, jitter_factor)
+fn calculate_delay_constant(_attempt: i64, delay: f64, jitter: JitterArg) -> f64 {
+    apply_jitter(delay, jitter)
 }
 
 /// Calculate delay using Fibonacci backoff with optional jitter
@@ -65,7 +167,7 @@ fn calculate_delay_constant(_attempt: i64, delay: f64, jitter_factor: f64) -> f6
 /// * `attempt` - The current attempt number (1-indexed)
 /// * `base_delay` - Base delay in seconds (multiplied by Fibonacci number)
 /// * `max_delay` - Maximum delay cap in seconds
-/// * `jitter_factor` - Jitter multiplier (0.0 = no jitter, 1.0 = full jitter)
+/// * `jitter` - Jitter factor or mode (see [`JitterArg`])
 ///
 /// # Returns
 /// Fibonacci-based delay with jitter applied
@@ -73,15 +175,75 @@ fn calculate_delay_fibonacci(
     attempt: i64,
     base_delay: f64,
     max_delay: f64,
-    jitter_factor: f64,
+    jitter: JitterArg,
 ) -> f64 {
-    let jitter_factor = normalize_jitter(jitter_factor);
-    let attempt_u8 = attempt.clamp(1, 255) as u8;
+    let attempt = attempt.clamp(1, u32::MAX as i64) as u32;
 
-    let fib = fibonacci(attempt_u8);
-    let base = (base_delay * fib as f64).min(max_delay);
+    let fib = fibonacci(attempt);
+    let base = cap_delay(base_delay * fib as f64, max_delay);
 
-    apply_jitter(base, jitter_factor)
+    apply_jitter(base, jitter)
+}
+
+/// Calculate delay using linear backoff with optional jitter
+///
+/// # Arguments
+/// * `attempt` - The current attempt number (1-indexed)
+/// * `base_delay` - Delay before the first retry in seconds
+/// * `step` - Seconds added to the delay on every subsequent attempt
+/// * `max_delay` - Maximum delay cap in seconds
+/// * `jitter` - Jitter factor or mode (see [`JitterArg`])
+///
+/// # Returns
+/// Linear delay with jitter applied
+fn calculate_delay_linear(
+    attempt: i64,
+    base_delay: f64,
+    step: f64,
+    max_delay: f64,
+    jitter: JitterArg,
+) -> f64 {
+    let attempt = attempt.clamp(1, u32::MAX as i64) as u32;
+    let steps = (attempt - 1) as f64;
+
+    let base = cap_delay(base_delay + step * steps, max_delay);
+
+    apply_jitter(base, jitter)
+}
+
+/// Calculate delay using polynomial backoff with optional jitter
+///
+/// # Arguments
+/// * `attempt` - The current attempt number (1-indexed)
+/// * `base_delay` - Base delay in seconds (multiplied by attempt^exponent)
+/// * `exponent` - Integer power applied to the attempt number
+/// * `max_delay` - Maximum delay cap in seconds
+/// * `jitter` - Jitter factor or mode (see [`JitterArg`])
+///
+/// # Returns
+/// Polynomial delay with jitter applied
+fn calculate_delay_polynomial(
+    attempt: i64,
+    base_delay: f64,
+    exponent: i64,
+    max_delay: f64,
+    jitter: JitterArg,
+) -> f64 {
+    let attempt = attempt.clamp(1, u32::MAX as i64) as u32;
+    let exponent = exponent.clamp(0, i32::MAX as i64) as i32;
+
+    let base = cap_delay(base_delay * (attempt as f64).powi(exponent), max_delay);
+
+    apply_jitter(base, jitter)
+}
+
+/// Cap a computed delay, treating `NaN` (e.g. zero base times an overflowed growth factor) as zero
+fn cap_delay(delay: f64, max_delay: f64) -> f64 {
+    if delay.is_nan() {
+        0.0
+    } else {
+        delay.min(max_delay)
+    }
 }
 
 /// Normalize jitter factor to [0.0, 1.0] range
@@ -93,13 +255,20 @@ fn normalize_jitter(jitter_factor: f64) -> f64 {
     }
 }
 
-/// Apply jitter to a base delay value
-fn apply_jitter(base: f64, jitter_factor: f64) -> f64 {
+/// Apply jitter to a base delay value in seconds
+///
+/// [`Jitter`] works in milliseconds, so the delay is converted on the way in
+/// and out; fractional milliseconds are preserved.
+fn apply_jitter(base: f64, jitter: JitterArg) -> f64 {
     RNG.with(|rng| {
         let mut rng = rng.borrow_mut();
-        let random_scalar: f64 = rng.random_range(0.0..=1.0);
-        let jitter_blend = 1.0 - jitter_factor + random_scalar * jitter_factor;
-        base * jitter_blend
+        match jitter {
+            JitterArg::Mode(jitter) => jitter.apply(base * 1000.0, &mut *rng) / 1000.0,
+            JitterArg::Bounded { min, max } => {
+                let (low, high) = if min <= max { (min, max) } else { (max, min) };
+                (base + rng.random_range(low..=high)).max(0.0)
+            }
+        }
     })
 }
 
@@ -116,6 +285,11 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     )?;
     module.define_module_function("constant_delay", function!(calculate_delay_constant, 3))?;
     module.define_module_function("fibonacci_delay", function!(calculate_delay_fibonacci, 4))?;
+    module.define_module_function("linear_delay", function!(calculate_delay_linear, 5))?;
+    module.define_module_function(
+        "polynomial_delay",
+        function!(calculate_delay_polynomial, 5),
+    )?;
 
     // Backward compatibility: alias old name to exponential
     module.define_module_function("calculate_delay", function!(calculate_delay_exponential, 5))?;
@@ -134,10 +308,10 @@ mod tests {
             *rng.borrow_mut() = StdRng::seed_from_u64(1337);
         });
 
-        let delay = calculate_delay_exponential(1, 0.0004, 1.0, 0.001, 0.5);
+        let delay = calculate_delay_exponential(1, 0.0004, 1.0, 0.001, 0.5.into());
 
         assert!(
-            delay >= 0.0002 && delay <= 0.0004,
+            (0.0002..=0.0004).contains(&delay),
             "expected delay in [0.0002, 0.0004], got {delay}"
         );
     }
@@ -149,11 +323,11 @@ mod tests {
         });
 
         // Constant delay with 10% jitter should be 90-100% of base
-        let delay = calculate_delay_constant(5, 1.0, 0.1);
-        assert!(delay >= 0.9 && delay <= 1.0, "got {delay}");
+        let delay = calculate_delay_constant(5, 1.0, 0.1.into());
+        assert!((0.9..=1.0).contains(&delay), "got {delay}");
 
         // No jitter should return exact value
-        let delay = calculate_delay_constant(3, 0.5, 0.0);
+        let delay = calculate_delay_constant(3, 0.5, 0.0.into());
         assert_eq!(delay, 0.5);
     }
 
@@ -165,16 +339,83 @@ mod tests {
 
         // Fibonacci sequence: 1, 1, 2, 3, 5, 8, 13...
         // Attempt 1: base_delay * 1 = 0.1
-        let delay1 = calculate_delay_fibonacci(1, 0.1, 10.0, 0.0);
+        let delay1 = calculate_delay_fibonacci(1, 0.1, 10.0, 0.0.into());
         assert_eq!(delay1, 0.1);
 
         // Attempt 5: base_delay * 5 = 0.5
-        let delay5 = calculate_delay_fibonacci(5, 0.1, 10.0, 0.0);
+        let delay5 = calculate_delay_fibonacci(5, 0.1, 10.0, 0.0.into());
         assert_eq!(delay5, 0.5);
 
         // Attempt 8: base_delay * 21 = 2.1
-        let delay8 = calculate_delay_fibonacci(8, 0.1, 10.0, 0.0);
+        let delay8 = calculate_delay_fibonacci(8, 0.1, 10.0, 0.0.into());
         assert_eq!(delay8, 2.1);
+
+        // Capped like the other strategies, with NaN treated as zero
+        assert_eq!(calculate_delay_fibonacci(50, 0.1, 2.0, 0.0.into()), 2.0);
+        assert_eq!(calculate_delay_fibonacci(3, f64::NAN, 2.0, 0.0.into()), 0.0);
+    }
+
+    #[test]
+    fn test_linear_delay() {
+        // base + step * (attempt - 1)
+        let delay = calculate_delay_linear(1, 0.1, 0.25, 10.0, 0.0.into());
+        assert_eq!(delay, 0.1);
+
+        let delay = calculate_delay_linear(3, 0.1, 0.25, 10.0, 0.0.into());
+        assert_eq!(delay, 0.6);
+
+        // Capped at max_delay
+        let delay = calculate_delay_linear(50, 0.1, 0.25, 2.0, 0.0.into());
+        assert_eq!(delay, 2.0);
+
+        // NaN inputs collapse to zero like the other strategies
+        let delay = calculate_delay_linear(3, f64::NAN, 0.25, 2.0, 0.0.into());
+        assert_eq!(delay, 0.0);
+    }
+
+    #[test]
+    fn test_polynomial_delay() {
+        // base * attempt^exponent
+        let delay = calculate_delay_polynomial(3, 0.01, 2, 10.0, 0.0.into());
+        assert!((delay - 0.09).abs() < 1e-12, "got {delay}");
+
+        let delay = calculate_delay_polynomial(2, 0.01, 3, 10.0, 0.0.into());
+        assert!((delay - 0.08).abs() < 1e-12, "got {delay}");
+
+        // Capped at max_delay
+        let delay = calculate_delay_polynomial(100, 0.01, 3, 5.0, 0.0.into());
+        assert_eq!(delay, 5.0);
+    }
+
+    #[test]
+    fn test_jitter_modes_match_core() {
+        RNG.with(|rng| {
+            *rng.borrow_mut() = StdRng::seed_from_u64(7);
+        });
+
+        // No jitter keeps sub-millisecond precision
+        let delay = calculate_delay_constant(1, 0.0001, JitterArg::Mode(Jitter::None));
+        assert!((delay - 0.0001).abs() < 1e-12, "got {delay}");
+
+        // Equal jitter keeps at least half of the delay
+        let delay = calculate_delay_exponential(2, 0.5, 2.0, 10.0, JitterArg::Mode(Jitter::Equal));
+        assert!((0.5..=1.0).contains(&delay), "got {delay}");
+
+        // Bounded jitter adds an offset given in seconds
+        let bounded = JitterArg::Bounded {
+            min: 0.01,
+            max: 0.02,
+        };
+        let delay = calculate_delay_fibonacci(3, 0.1, 10.0, bounded);
+        assert!((0.21..=0.22).contains(&delay), "got {delay}");
+
+        // Sub-millisecond bounds are not rounded away
+        let bounded = JitterArg::Bounded {
+            min: 0.0002,
+            max: 0.0004,
+        };
+        let delay = calculate_delay_constant(1, 0.001, bounded);
+        assert!((0.0012..=0.0014).contains(&delay), "got {delay}");
     }
 
     #[test]
@@ -11,7 +11,7 @@ module ChronoMachines
       @engine_override = nil # nil = auto-detect, :ruby = force Ruby, :native = force native
       @policies = {
         DEFAULT_POLICY_NAME => {
-          backoff_strategy: :exponential, # :exponential, :constant, or :fibonacci
+          backoff_strategy: :exponential, # :exponential, :constant, :fibonacci, :linear or :polynomial
           max_attempts: 3,
           base_delay: 0.1, # seconds
           multiplier: 2,   # For exponential backoff
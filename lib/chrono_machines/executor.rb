@@ -2,6 +2,8 @@
 
 module ChronoMachines
   class Executor
+    JITTER_MODES = %i[none full equal proportional bounded gaussian].freeze
+
     def initialize(policy_or_options = {})
       policy_options = if policy_or_options.is_a?(Symbol)
                          ChronoMachines.config.get_policy(policy_or_options)
@@ -13,6 +15,8 @@ module ChronoMachines
       @max_attempts = policy_options[:max_attempts]
       @base_delay = policy_options[:base_delay]
       @multiplier = policy_options[:multiplier] || 2
+      @step = policy_options[:step] || @base_delay
+      @exponent = policy_options[:exponent] || 2
       @max_delay = policy_options[:max_delay]
       @jitter_factor = policy_options[:jitter_factor]
       @retryable_exceptions = policy_options[:retryable_exceptions]
@@ -63,22 +67,52 @@ module ChronoMachines
     # Pure Ruby implementation of delay calculation (exponential backoff)
     def ruby_calculate_delay_exponential(attempts)
       base_exponential_delay = [@base_delay * (@multiplier**(attempts - 1)), @max_delay].min
-      jitter_factor = normalized_jitter_factor
-      base_exponential_delay * (1 - jitter_factor + (rand * jitter_factor))
+      apply_jitter(base_exponential_delay)
     end
 
     # Pure Ruby implementation of constant backoff
     def ruby_calculate_delay_constant(_attempts)
-      jitter_factor = normalized_jitter_factor
-      @base_delay * (1 - jitter_factor + (rand * jitter_factor))
+      apply_jitter(@base_delay)
     end
 
     # Pure Ruby implementation of Fibonacci backoff
     def ruby_calculate_delay_fibonacci(attempts)
       fib = fibonacci(attempts)
       base_delay = [@base_delay * fib, @max_delay].min
-      jitter_factor = normalized_jitter_factor
-      base_delay * (1 - jitter_factor + (rand * jitter_factor))
+      apply_jitter(base_delay)
+    end
+
+    # Pure Ruby implementation of the jitter modes, matching the native extension
+    def apply_jitter(delay)
+      mode, *args = jitter_spec
+      jittered = case mode
+                 when :none then delay
+                 when :full then delay * rand
+                 when :equal then (delay / 2.0) + (delay / 2.0 * rand)
+                 when :proportional then delay * (1 - args[0] + (rand * args[0]))
+                 when :bounded
+                   low, high = args.minmax
+                   delay + low + (rand * (high - low))
+                 when :gaussian
+                   # Irwin-Hall approximation of a standard normal sample
+                   delay + (delay * args[0] * (Array.new(12) { rand }.sum - 6.0))
+                 end
+      [jittered, 0.0].max
+    end
+
+    # Pure Ruby implementation of linear backoff
+    def ruby_calculate_delay_linear(attempts)
+      apply_jitter(capped_delay(@base_delay + (@step * (attempts - 1))))
+    end
+
+    # Pure Ruby implementation of polynomial backoff
+    def ruby_calculate_delay_polynomial(attempts)
+      apply_jitter(capped_delay(@base_delay * (attempts**@exponent)))
+    end
+
+    # Cap a computed delay, treating NaN as zero like the native extension
+    def capped_delay(delay)
+      delay.to_f.nan? ? 0.0 : [delay, @max_delay].min
     end
 
     # Calculate Fibonacci number (1-indexed)
@@ -102,6 +136,10 @@ module ChronoMachines
         ruby_calculate_delay_constant(attempts)
       when :fibonacci
         ruby_calculate_delay_fibonacci(attempts)
+      when :linear
+        ruby_calculate_delay_linear(attempts)
+      when :polynomial
+        ruby_calculate_delay_polynomial(attempts)
       else
         raise ArgumentError, "Unknown backoff strategy: #{@backoff_strategy}"
       end
@@ -138,8 +176,49 @@ module ChronoMachines
       end
     end
 
-    def normalized_jitter_factor
-      factor = Float(@jitter_factor)
+    # Normalize jitter_factor to [mode, *args], the form both the Ruby and
+    # native implementations accept. jitter_factor may be a number
+    # (proportional jitter), :none, :full, :equal, [:proportional, factor],
+    # [:bounded, min_seconds, max_seconds] or [:gaussian, stddev].
+    def jitter_spec
+      spec = case @jitter_factor
+             when Array then @jitter_factor
+             when Symbol then [@jitter_factor]
+             when String then [@jitter_factor] if JITTER_MODES.include?(@jitter_factor.to_sym)
+             end
+      return [:proportional, normalized_jitter_factor] if spec.nil?
+
+      mode = spec.first.respond_to?(:to_sym) ? spec.first.to_sym : spec.first
+      case mode
+      when :none, :full, :equal
+        [mode]
+      when :proportional
+        [mode, normalized_jitter_factor(jitter_argument(spec, 1))]
+      when :bounded
+        [mode, non_negative_seconds(jitter_argument(spec, 1)), non_negative_seconds(jitter_argument(spec, 2))]
+      when :gaussian
+        stddev = jitter_argument(spec, 1)
+        [mode, stddev.nan? ? 0.0 : stddev.abs]
+      else
+        raise ArgumentError, "unknown jitter mode :#{mode}"
+      end
+    end
+
+    def jitter_argument(spec, index)
+      value = spec[index]
+      raise ArgumentError, "jitter mode :#{spec.first} is missing argument #{index}" if value.nil?
+
+      Float(value)
+    rescue TypeError
+      raise ArgumentError, "jitter mode :#{spec.first} argument #{index} must be numeric"
+    end
+
+    def non_negative_seconds(seconds)
+      seconds.nan? || seconds.negative? ? 0.0 : seconds
+    end
+
+    def normalized_jitter_factor(value = @jitter_factor)
+      factor = Float(value)
       raise ArgumentError, 'jitter_factor cannot be NaN' if factor.nan?
 
       factor.clamp(0.0, 1.0)
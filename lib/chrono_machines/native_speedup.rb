@@ -29,20 +29,36 @@ begin
             @base_delay,
             @multiplier,
             @max_delay,
-            normalized_jitter_factor
+            jitter_spec
           )
         when :constant
           ChronoMachinesNative.constant_delay(
             attempts,
             @base_delay,
-            normalized_jitter_factor
+            jitter_spec
           )
         when :fibonacci
           ChronoMachinesNative.fibonacci_delay(
             attempts,
             @base_delay,
             @max_delay,
-            normalized_jitter_factor
+            jitter_spec
+          )
+        when :linear
+          ChronoMachinesNative.linear_delay(
+            attempts,
+            @base_delay,
+            @step,
+            @max_delay,
+            jitter_spec
+          )
+        when :polynomial
+          ChronoMachinesNative.polynomial_delay(
+            attempts,
+            @base_delay,
+            @exponent,
+            @max_delay,
+            jitter_spec
           )
         else
           # Unknown strategy, fall back to Ruby
@@ -106,6 +106,21 @@ class ExecutorTest < Minitest::Test
     assert_operator delay, :<=, 0.0004
   end
 
+  def test_linear_and_polynomial_strategies
+    linear = ChronoMachines::Executor.new(backoff_strategy: :linear, base_delay: 0.1, step: 0.25,
+                                          max_delay: 2, jitter_factor: 0.0)
+    polynomial = ChronoMachines::Executor.new(backoff_strategy: :polynomial, base_delay: 0.01, exponent: 3,
+                                              max_delay: 5, jitter_factor: 0.0)
+
+    # calculate_delay uses the native extension when it is loaded
+    %i[calculate_delay ruby_calculate_delay].each do |method|
+      assert_in_delta 0.6, linear.send(method, 3), 1e-9
+      assert_in_delta 2.0, linear.send(method, 50), 1e-9
+      assert_in_delta 0.08, polynomial.send(method, 2), 1e-9
+      assert_in_delta 5.0, polynomial.send(method, 100), 1e-9
+    end
+  end
+
   def test_robust_sleep_handles_zero_delay
     executor = ChronoMachines::Executor.new
 
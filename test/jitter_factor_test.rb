@@ -82,4 +82,40 @@ class JitterFactorTest < Minitest::Test
       end
     end
   end
+
+  def test_jitter_modes_in_ruby_implementation
+    build = lambda do |jitter|
+      ChronoMachines::Executor.new(base_delay: 1.0, multiplier: 1.0, max_delay: 10.0, jitter_factor: jitter)
+    end
+
+    assert_in_delta 1.0, build.call(:none).send(:ruby_calculate_delay, 1), 0.001
+
+    20.times do
+      assert_operator build.call(:full).send(:ruby_calculate_delay, 1), :<=, 1.0
+      assert_includes 0.5..1.0, build.call('equal').send(:ruby_calculate_delay, 1)
+      assert_includes 0.9..1.0, build.call([:proportional, 0.1]).send(:ruby_calculate_delay, 1)
+      assert_includes 1.0002..1.0004, build.call([:bounded, 0.0002, 0.0004]).send(:ruby_calculate_delay, 1)
+      assert_operator build.call([:gaussian, 0.1]).send(:ruby_calculate_delay, 1), :>=, 0.0
+    end
+  end
+
+  def test_jitter_modes_match_between_implementations
+    executor = ChronoMachines::Executor.new(base_delay: 1.0, multiplier: 1.0, max_delay: 10.0,
+                                            jitter_factor: [:bounded, 0.1, 0.2])
+
+    # calculate_delay uses the native extension when it is loaded
+    20.times do
+      assert_includes 1.1..1.2, executor.send(:calculate_delay, 1)
+      assert_includes 1.1..1.2, executor.send(:ruby_calculate_delay, 1)
+    end
+  end
+
+  def test_invalid_jitter_modes_rejected
+    [:jumpy, [:bounded, 0.1], [:gaussian, 'wide']].each do |jitter|
+      executor = ChronoMachines::Executor.new(base_delay: 1.0, jitter_factor: jitter)
+
+      assert_raises(ArgumentError) { executor.send(:calculate_delay, 1) }
+      assert_raises(ArgumentError) { executor.send(:ruby_calculate_delay, 1) }
+    end
+  end
 end